@@ -1,28 +1,41 @@
 #![no_std]
 
+use embedded_graphics::image::ImageRaw;
 use embedded_graphics::mono_font::ascii::{FONT_6X10, FONT_7X13_BOLD};
-use embedded_graphics::{mono_font::MonoTextStyle, pixelcolor::BinaryColor, prelude::*};
+use embedded_graphics::{pixelcolor::BinaryColor, prelude::*};
 use embedded_graphics_simulator::sdl2::Keycode;
 use embedded_graphics_simulator::{
     BinaryColorTheme, OutputSettingsBuilder, SimulatorDisplay, SimulatorEvent, Window,
 };
 use log::info;
-use simple_embedded_graphics_menu::{Menu, MenuStyle};
+use simple_embedded_graphics_menu::header::{HeaderAlignment, HeaderStyle};
+use simple_embedded_graphics_menu::icon::MenuIcon;
+use simple_embedded_graphics_menu::theme::MenuTheme;
+use simple_embedded_graphics_menu::{Menu, MenuStyleBuilder};
 
-fn build_menu<'a>() -> Menu<'a, BinaryColor, i32> {
-    let heading_style = MonoTextStyle::new(&FONT_7X13_BOLD, BinaryColor::On);
-    let item_style = MonoTextStyle::new(&FONT_6X10, BinaryColor::On);
-    let highlighted_item_style = MonoTextStyle::new(&FONT_6X10, BinaryColor::Off);
+#[rustfmt::skip]
+static GEAR_ICON: ImageRaw<BinaryColor> = ImageRaw::new(
+    &[
+        0b00011000,
+        0b01011010,
+        0b00111100,
+        0b11100111,
+        0b11100111,
+        0b00111100,
+        0b01011010,
+        0b00011000,
+    ],
+    8,
+);
 
-    let menu_style = MenuStyle::new(
-        BinaryColor::Off,
-        heading_style,
-        item_style,
-        BinaryColor::On,
-        BinaryColor::On,
-        highlighted_item_style,
-        BinaryColor::Off,
-    );
+fn build_menu<'a>() -> Menu<'a, BinaryColor, i32> {
+    let menu_style = MenuStyleBuilder::new(&FONT_7X13_BOLD, &FONT_6X10, MenuTheme::high_contrast())
+        .header_style(HeaderStyle {
+            alignment: HeaderAlignment::Left,
+            breadcrumbs: true,
+            underline: true,
+        })
+        .build();
 
     let mut counter = 0..100;
     let mut menu_root = Menu::new("M1 Heading", counter.next().unwrap_or(0i32), menu_style);
@@ -70,7 +83,14 @@ fn build_menu<'a>() -> Menu<'a, BinaryColor, i32> {
         options,
         Some(2),
     );
-    menu_root.add_action("Action 1", counter.next().unwrap_or(0i32));
+    menu_root.add_slider("Volume", counter.next().unwrap_or(0i32), 0, 10, 5);
+    let action_id = counter.next().unwrap_or(0i32);
+    menu_root.add_action("Action 1", action_id);
+    menu_root.set_icon(action_id, Some(MenuIcon::new(&GEAR_ICON)));
+    menu_root.set_help_text(
+        action_id,
+        Some("Runs the first action. Press H on any item with help text to show it here."),
+    );
     menu_root.add_exit("Exit", counter.next().unwrap_or(0i32));
 
     menu_root
@@ -88,7 +108,7 @@ fn main() -> Result<(), core::convert::Infallible> {
     let mut window = Window::new("Menu test", &output_settings);
 
     'gui_update_loop: loop {
-        menu.draw(&mut display)?;
+        menu.draw_changes(&mut display)?;
         window.update(&display);
 
         for event in window.events() {
@@ -98,6 +118,11 @@ fn main() -> Result<(), core::convert::Infallible> {
                     match keycode {
                         Keycode::Up => menu.navigate_up(),
                         Keycode::Down => menu.navigate_down(),
+                        Keycode::Left => menu.navigate_left(),
+                        Keycode::Right => menu.navigate_right(),
+                        Keycode::H => {
+                            menu.show_help();
+                        }
                         Keycode::Return => {
                             if let Some(selected_data) = menu.select_item() {
                                 info!("{:?}", selected_data);
@@ -0,0 +1,86 @@
+use crate::MenuStyle;
+use embedded_graphics::draw_target::DrawTarget;
+use embedded_graphics::geometry::Point;
+use embedded_graphics::mono_font::MonoTextStyle;
+use embedded_graphics::pixelcolor::PixelColor;
+use embedded_graphics::prelude::Primitive;
+use embedded_graphics::primitives::{Line, PrimitiveStyle, Rectangle};
+use embedded_graphics::text::{Alignment, Baseline, Text, TextStyleBuilder};
+use embedded_graphics::Drawable;
+
+/// Labels for the left, centre and right soft keys shown in the footer. Empty labels leave that
+/// position blank.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SoftKeyHints {
+    pub left: &'static str,
+    pub center: &'static str,
+    pub right: &'static str,
+}
+
+impl SoftKeyHints {
+    pub const fn new(left: &'static str, center: &'static str, right: &'static str) -> Self {
+        Self {
+            left,
+            center,
+            right,
+        }
+    }
+}
+
+/// Draw the soft key hints into `footer_area`, on the header background with a separator line
+/// along the top if the style has them
+pub(crate) fn draw_footer<D, C>(
+    display: &mut D,
+    footer_area: Rectangle,
+    hints: SoftKeyHints,
+    menu_style: &MenuStyle<'_, C>,
+) -> Result<(), D::Error>
+where
+    D: DrawTarget<Color = C>,
+    C: PixelColor,
+{
+    if let Some(header_background_color) = menu_style.header_background_color {
+        footer_area
+            .into_styled(PrimitiveStyle::with_fill(header_background_color))
+            .draw(display)?;
+    }
+    if let (Some(separator_color), Some(bottom_right)) =
+        (menu_style.separator_color, footer_area.bottom_right())
+    {
+        Line::new(
+            footer_area.top_left,
+            Point::new(bottom_right.x, footer_area.top_left.y),
+        )
+        .into_styled(PrimitiveStyle::with_stroke(separator_color, 1))
+        .draw(display)?;
+    }
+
+    // Footer text matches the heading colour so it reads on the header background, at item size
+    let mut text_style: MonoTextStyle<'_, C> = menu_style.item_character_style;
+    text_style.text_color = menu_style.heading_character_style.text_color;
+
+    let top = footer_area.top_left.y + 1;
+    let left = footer_area.top_left.x;
+    let width = footer_area.size.width as i32;
+    for (hint, x, alignment) in [
+        (hints.left, left, Alignment::Left),
+        (hints.center, left + width / 2, Alignment::Center),
+        (hints.right, left + width, Alignment::Right),
+    ] {
+        if hint.is_empty() {
+            continue;
+        }
+        Text::with_text_style(
+            hint,
+            Point::new(x, top),
+            text_style,
+            TextStyleBuilder::new()
+                .alignment(alignment)
+                .baseline(Baseline::Top)
+                .build(),
+        )
+        .draw(display)?;
+    }
+
+    Ok(())
+}
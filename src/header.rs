@@ -0,0 +1,206 @@
+use crate::icon::MenuIcon;
+use crate::MenuStyle;
+use embedded_graphics::draw_target::{DrawTarget, DrawTargetExt};
+use embedded_graphics::geometry::{AnchorX, Point};
+use embedded_graphics::pixelcolor::PixelColor;
+use embedded_graphics::prelude::Primitive;
+use embedded_graphics::primitives::{Line, PrimitiveStyle, Rectangle};
+use embedded_graphics::text::{Baseline, Text};
+use embedded_graphics::Drawable;
+
+/// Most status icons that can be shown on the right of the header
+pub const MAX_STATUS_ICONS: usize = 4;
+
+const BREADCRUMB_SEPARATOR: &str = " > ";
+const TRUNCATION_MARKER: &str = "..";
+const STATUS_ICON_GAP: u32 = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HeaderAlignment {
+    Left,
+    Center,
+}
+
+/// Layout of the header drawn above the menu items. The header background and underline colours
+/// come from the menu style's header background and separator colours.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HeaderStyle {
+    pub alignment: HeaderAlignment,
+    /// Show the path through parent menus ("Settings > Display") instead of only the active menu
+    /// label. Paths too wide for the header are truncated from the left.
+    pub breadcrumbs: bool,
+    /// Draw a line along the bottom of the header, in the separator colour if there is one or the
+    /// heading colour otherwise
+    pub underline: bool,
+}
+
+impl Default for HeaderStyle {
+    fn default() -> Self {
+        Self {
+            alignment: HeaderAlignment::Left,
+            breadcrumbs: false,
+            underline: false,
+        }
+    }
+}
+
+/// Draw the header into `header_area`: background, title from `labels` joined as breadcrumbs,
+/// status icons on the right and the underline
+pub(crate) fn draw_header<D, C>(
+    display: &mut D,
+    header_area: Rectangle,
+    labels: &[&str],
+    status_icons: &[Option<MenuIcon<'_, C>>],
+    menu_style: &MenuStyle<'_, C>,
+) -> Result<(), D::Error>
+where
+    D: DrawTarget<Color = C>,
+    C: PixelColor,
+{
+    let header_style = menu_style.header_style;
+    if let Some(header_background_color) = menu_style.header_background_color {
+        header_area
+            .into_styled(PrimitiveStyle::with_fill(header_background_color))
+            .draw(display)?;
+    }
+
+    // Status icons are placed right to left, vertically centred
+    let mut status_left = header_area.top_left.x + header_area.size.width as i32;
+    for icon in status_icons.iter().flatten() {
+        let icon_size = icon.size();
+        status_left -= icon_size.width as i32;
+        let icon_top =
+            header_area.top_left.y + (header_area.size.height as i32 - icon_size.height as i32) / 2;
+        icon.draw(display, Point::new(status_left, icon_top))?;
+        status_left -= STATUS_ICON_GAP as i32;
+    }
+
+    let title_width = (status_left - header_area.top_left.x).max(0) as u32;
+    let title_area = header_area.resized_width(title_width, AnchorX::Left);
+    draw_title(
+        &mut display.clipped(&title_area).cropped(&title_area),
+        labels,
+        header_style.alignment,
+        menu_style,
+    )?;
+
+    if header_style.underline {
+        let underline_color = menu_style
+            .separator_color
+            .or(menu_style.heading_character_style.text_color);
+        if let (Some(underline_color), Some(bottom_right)) =
+            (underline_color, header_area.bottom_right())
+        {
+            Line::new(
+                Point::new(header_area.top_left.x, bottom_right.y),
+                bottom_right,
+            )
+            .into_styled(PrimitiveStyle::with_stroke(underline_color, 1))
+            .draw(display)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Draw the labels joined by the breadcrumb separator. If they don't fit, characters are dropped
+/// from the start and replaced by a truncation marker so the active menu label stays visible.
+fn draw_title<D, C>(
+    display: &mut D,
+    labels: &[&str],
+    alignment: HeaderAlignment,
+    menu_style: &MenuStyle<'_, C>,
+) -> Result<(), D::Error>
+where
+    D: DrawTarget<Color = C>,
+    C: PixelColor,
+{
+    let character_style = menu_style.heading_character_style;
+    let character_advance =
+        character_style.font.character_size.width + character_style.font.character_spacing;
+    let title_area_width = display.bounding_box().size.width;
+    let available_characters = (title_area_width / character_advance) as usize;
+
+    let title_characters: usize = labels
+        .iter()
+        .map(|label| label.chars().count())
+        .sum::<usize>()
+        + labels.len().saturating_sub(1) * BREADCRUMB_SEPARATOR.len();
+
+    let mut position = Point::zero();
+    let mut skip_characters = 0;
+    if title_characters > available_characters {
+        if available_characters <= TRUNCATION_MARKER.len() {
+            // Not even one character of the title fits beside the marker
+            return Ok(());
+        }
+        skip_characters = title_characters - available_characters + TRUNCATION_MARKER.len();
+        position = Text::with_baseline(TRUNCATION_MARKER, position, character_style, Baseline::Top)
+            .draw(display)?;
+    } else if alignment == HeaderAlignment::Center {
+        position.x = ((title_area_width - title_characters as u32 * character_advance) / 2) as i32;
+    }
+
+    for (index, label) in labels.iter().enumerate() {
+        let separator = match index {
+            0 => "",
+            _ => BREADCRUMB_SEPARATOR,
+        };
+        for part in [separator, label] {
+            let part_characters = part.chars().count();
+            if skip_characters >= part_characters {
+                skip_characters -= part_characters;
+                continue;
+            }
+            let visible_start = part
+                .char_indices()
+                .nth(skip_characters)
+                .map_or(part.len(), |(byte_index, _)| byte_index);
+            skip_characters = 0;
+            position = Text::with_baseline(
+                &part[visible_start..],
+                position,
+                character_style,
+                Baseline::Top,
+            )
+            .draw(display)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::MenuTheme;
+    use crate::MenuStyleBuilder;
+    use embedded_graphics::geometry::Size;
+    use embedded_graphics::image::ImageRaw;
+    use embedded_graphics::mock_display::MockDisplay;
+    use embedded_graphics::mono_font::ascii::FONT_6X10;
+    use embedded_graphics::pixelcolor::BinaryColor;
+
+    #[test]
+    fn titles_with_no_room_left_beside_the_status_icons_are_left_out() {
+        let menu_style = MenuStyleBuilder::new(&FONT_6X10, &FONT_6X10, MenuTheme::dark()).build();
+        // 50 pixels wide, leaving 8 pixels beside it for the title: one character, too few for
+        // the truncation marker and any of the title
+        let image = ImageRaw::<BinaryColor>::new(&[0xff; 70], 50);
+        let icon = MenuIcon::new(&image);
+        let mut display = MockDisplay::new();
+        let header_area = Rectangle::new(Point::zero(), Size::new(60, 10));
+        draw_header(
+            &mut display,
+            header_area,
+            &["Settings"],
+            &[Some(icon)],
+            &menu_style,
+        )
+        .unwrap();
+        assert_eq!(
+            display.affected_area(),
+            Rectangle::new(Point::new(10, 0), Size::new(50, 10))
+        );
+    }
+}
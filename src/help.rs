@@ -0,0 +1,197 @@
+use core::cell::Cell;
+use core::iter::once;
+use embedded_graphics::draw_target::DrawTarget;
+use embedded_graphics::geometry::Point;
+use embedded_graphics::mono_font::MonoTextStyle;
+use embedded_graphics::pixelcolor::PixelColor;
+use embedded_graphics::primitives::Rectangle;
+use embedded_graphics::text::renderer::TextRenderer;
+use embedded_graphics::text::{Baseline, Text};
+use embedded_graphics::Drawable;
+
+/// Lines of `text` broken at spaces so each fits in `width` when drawn with `style`. Line breaks
+/// in the text are kept and words wider than a line are split between characters.
+pub(crate) struct WrappedLines<'t, S> {
+    remaining: &'t str,
+    style: S,
+    width: u32,
+}
+
+impl<'t, S> WrappedLines<'t, S>
+where
+    S: TextRenderer,
+{
+    pub fn new(text: &'t str, style: S, width: u32) -> Self {
+        Self {
+            remaining: text,
+            style,
+            width,
+        }
+    }
+
+    fn fits(&self, line: &str) -> bool {
+        self.style
+            .measure_string(line, Point::zero(), Baseline::Top)
+            .bounding_box
+            .size
+            .width
+            <= self.width
+    }
+}
+
+impl<'t, S> Iterator for WrappedLines<'t, S>
+where
+    S: TextRenderer,
+{
+    type Item = &'t str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining.is_empty() {
+            return None;
+        }
+        let paragraph_end = self.remaining.find('\n').unwrap_or(self.remaining.len());
+        let paragraph = &self.remaining[..paragraph_end];
+
+        let mut line_end = None;
+        for (index, _) in paragraph
+            .match_indices(' ')
+            .chain(once((paragraph.len(), "")))
+        {
+            if !self.fits(&paragraph[..index]) {
+                break;
+            }
+            line_end = Some(index);
+        }
+        // Nothing fits up to a space, so break the first word at the last character that fits,
+        // keeping at least one character so every line makes progress
+        let line_end = line_end.unwrap_or_else(|| {
+            let mut ends = paragraph
+                .char_indices()
+                .map(|(index, character)| index + character.len_utf8());
+            let first_end = ends.next().unwrap_or(paragraph.len());
+            ends.take_while(|&end| self.fits(&paragraph[..end]))
+                .last()
+                .unwrap_or(first_end)
+        });
+
+        let line = paragraph[..line_end].trim_end_matches(' ');
+        let mut rest = self.remaining[line_end..].trim_start_matches(' ');
+        if let Some(next_paragraph) = rest.strip_prefix('\n') {
+            rest = next_paragraph;
+        }
+        self.remaining = rest;
+        Some(line)
+    }
+}
+
+/// Draw `text` word-wrapped into `area`, starting from its `first_line`th wrapped line and
+/// leaving out lines that don't fit. Returns the total number of wrapped lines.
+pub(crate) fn draw_wrapped<D, C>(
+    display: &mut D,
+    area: Rectangle,
+    text: &str,
+    first_line: usize,
+    text_style: MonoTextStyle<'_, C>,
+) -> Result<usize, D::Error>
+where
+    D: DrawTarget<Color = C>,
+    C: PixelColor,
+{
+    let line_height = text_style.line_height().max(1);
+    let visible_lines = (area.size.height / line_height) as usize;
+    let mut line_count = 0;
+    for (index, line) in WrappedLines::new(text, text_style, area.size.width).enumerate() {
+        line_count += 1;
+        if index < first_line || index >= first_line + visible_lines {
+            continue;
+        }
+        let line_top = area.top_left.y + ((index - first_line) as u32 * line_height) as i32;
+        Text::with_baseline(
+            line,
+            Point::new(area.top_left.x, line_top),
+            text_style,
+            Baseline::Top,
+        )
+        .draw(display)?;
+    }
+    Ok(line_count)
+}
+
+/// Scroll position of the full-screen help page, and the wrapped line counts found when it was
+/// last drawn
+pub(crate) struct HelpPage {
+    first_line: usize,
+    line_count: Cell<usize>,
+    visible_lines: Cell<usize>,
+}
+
+impl HelpPage {
+    pub fn new() -> Self {
+        Self {
+            first_line: 0,
+            line_count: Cell::new(0),
+            visible_lines: Cell::new(0),
+        }
+    }
+
+    pub fn first_line(&self) -> usize {
+        self.first_line
+    }
+
+    pub fn set_drawn_lines(&self, line_count: usize, visible_lines: usize) {
+        self.line_count.set(line_count);
+        self.visible_lines.set(visible_lines);
+    }
+
+    pub fn scroll_up(&mut self) {
+        self.first_line = self.first_line.saturating_sub(1);
+    }
+
+    /// Scroll down unless the last line is already showing
+    pub fn scroll_down(&mut self) {
+        if self.first_line + self.visible_lines.get() < self.line_count.get() {
+            self.first_line += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::mono_font::ascii::FONT_6X10;
+    use embedded_graphics::pixelcolor::BinaryColor;
+
+    /// The first four lines of `text` wrapped `width` wide in a 6 pixel wide font
+    fn wrap(text: &str, width: u32) -> [Option<&str>; 4] {
+        let style = MonoTextStyle::new(&FONT_6X10, BinaryColor::On);
+        let mut lines = WrappedLines::new(text, style, width);
+        [lines.next(), lines.next(), lines.next(), lines.next()]
+    }
+
+    #[test]
+    fn lines_break_at_spaces() {
+        assert_eq!(
+            wrap("one two three", 42),
+            [Some("one two"), Some("three"), None, None]
+        );
+        assert_eq!(wrap("one  two", 24), [Some("one"), Some("two"), None, None]);
+    }
+
+    #[test]
+    fn line_breaks_in_the_text_are_kept() {
+        assert_eq!(
+            wrap("one\n\ntwo", 60),
+            [Some("one"), Some(""), Some("two"), None]
+        );
+    }
+
+    #[test]
+    fn long_words_are_split() {
+        assert_eq!(
+            wrap("abcdefghij", 24),
+            [Some("abcd"), Some("efgh"), Some("ij"), None]
+        );
+        // Too narrow for any character still makes progress, one character a line
+        assert_eq!(wrap("ab", 0), [Some("a"), Some("b"), None, None]);
+    }
+}
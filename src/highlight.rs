@@ -0,0 +1,231 @@
+use crate::MenuStyle;
+use embedded_graphics::draw_target::DrawTarget;
+use embedded_graphics::geometry::{Point, Size};
+use embedded_graphics::pixelcolor::PixelColor;
+use embedded_graphics::prelude::Primitive;
+use embedded_graphics::primitives::{Line, PrimitiveStyle, Rectangle, RoundedRectangle};
+use embedded_graphics::text::renderer::TextRenderer;
+use embedded_graphics::text::{Baseline, Text};
+use embedded_graphics::Drawable;
+
+/// Strategy for marking the highlighted item. The renderer draws behind the item content, then
+/// the item draws its icon, label and value on top.
+pub(crate) trait HighlightRenderer<C: PixelColor> {
+    /// Draw the highlight for an item occupying `area`
+    fn draw_highlight<D>(
+        &self,
+        display: &mut D,
+        area: Rectangle,
+        menu_style: &MenuStyle<'_, C>,
+    ) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>;
+
+    /// Whether the highlighted item content is drawn with the highlight text style and colours
+    /// because it sits on top of a filled highlight
+    fn inverts_content(&self) -> bool;
+
+    /// Horizontal space reserved to the left of every item, highlighted or not
+    fn content_offset(&self, _menu_style: &MenuStyle<'_, C>) -> u32 {
+        0
+    }
+}
+
+/// Solid bar in the highlight colour behind the whole row
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct FilledBar;
+
+impl<C: PixelColor> HighlightRenderer<C> for FilledBar {
+    fn draw_highlight<D>(
+        &self,
+        display: &mut D,
+        area: Rectangle,
+        menu_style: &MenuStyle<'_, C>,
+    ) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        area.into_styled(PrimitiveStyle::with_fill(menu_style.highlight_item_color))
+            .draw(display)
+    }
+
+    fn inverts_content(&self) -> bool {
+        true
+    }
+}
+
+/// One pixel box in the highlight colour around the row
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct OutlineBox;
+
+impl<C: PixelColor> HighlightRenderer<C> for OutlineBox {
+    fn draw_highlight<D>(
+        &self,
+        display: &mut D,
+        area: Rectangle,
+        menu_style: &MenuStyle<'_, C>,
+    ) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        area.into_styled(PrimitiveStyle::with_stroke(
+            menu_style.highlight_item_color,
+            1,
+        ))
+        .draw(display)
+    }
+
+    fn inverts_content(&self) -> bool {
+        false
+    }
+}
+
+/// Solid bar with fully rounded ends behind the whole row
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct RoundedBar;
+
+impl<C: PixelColor> HighlightRenderer<C> for RoundedBar {
+    fn draw_highlight<D>(
+        &self,
+        display: &mut D,
+        area: Rectangle,
+        menu_style: &MenuStyle<'_, C>,
+    ) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let corner_radius = area.size.height / 2;
+        RoundedRectangle::with_equal_corners(area, Size::new(corner_radius, corner_radius))
+            .into_styled(PrimitiveStyle::with_fill(menu_style.highlight_item_color))
+            .draw(display)
+    }
+
+    fn inverts_content(&self) -> bool {
+        true
+    }
+}
+
+/// ">" cursor in front of the highlighted row. Space for the cursor is kept free on every row so
+/// labels don't shift as the highlight moves.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct ArrowCursor;
+
+const ARROW_CURSOR: &str = ">";
+
+impl<C: PixelColor> HighlightRenderer<C> for ArrowCursor {
+    fn draw_highlight<D>(
+        &self,
+        display: &mut D,
+        area: Rectangle,
+        menu_style: &MenuStyle<'_, C>,
+    ) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        Text::with_baseline(
+            ARROW_CURSOR,
+            area.top_left,
+            menu_style.item_character_style,
+            Baseline::Top,
+        )
+        .draw(display)?;
+        Ok(())
+    }
+
+    fn inverts_content(&self) -> bool {
+        false
+    }
+
+    fn content_offset(&self, menu_style: &MenuStyle<'_, C>) -> u32 {
+        menu_style
+            .item_character_style
+            .measure_string(ARROW_CURSOR, Point::zero(), Baseline::Top)
+            .next_position
+            .x as u32
+    }
+}
+
+/// Line in the highlight colour along the bottom of the row
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Underline;
+
+impl<C: PixelColor> HighlightRenderer<C> for Underline {
+    fn draw_highlight<D>(
+        &self,
+        display: &mut D,
+        area: Rectangle,
+        menu_style: &MenuStyle<'_, C>,
+    ) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        if let Some(bottom_right) = area.bottom_right() {
+            Line::new(Point::new(area.top_left.x, bottom_right.y), bottom_right)
+                .into_styled(PrimitiveStyle::with_stroke(
+                    menu_style.highlight_item_color,
+                    1,
+                ))
+                .draw(display)?;
+        }
+        Ok(())
+    }
+
+    fn inverts_content(&self) -> bool {
+        false
+    }
+}
+
+/// Selects one of the built-in highlight renderers for a [`MenuStyle`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HighlightStyle {
+    /// Solid bar in the highlight colour behind the whole row
+    FilledBar,
+    /// One pixel box in the highlight colour around the row
+    OutlineBox,
+    /// Solid bar with fully rounded ends behind the whole row
+    RoundedBar,
+    /// ">" cursor in front of the highlighted row, with space kept free for it on every row
+    ArrowCursor,
+    /// Line in the highlight colour along the bottom of the row
+    Underline,
+}
+
+impl<C: PixelColor> HighlightRenderer<C> for HighlightStyle {
+    fn draw_highlight<D>(
+        &self,
+        display: &mut D,
+        area: Rectangle,
+        menu_style: &MenuStyle<'_, C>,
+    ) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        match self {
+            HighlightStyle::FilledBar => FilledBar.draw_highlight(display, area, menu_style),
+            HighlightStyle::OutlineBox => OutlineBox.draw_highlight(display, area, menu_style),
+            HighlightStyle::RoundedBar => RoundedBar.draw_highlight(display, area, menu_style),
+            HighlightStyle::ArrowCursor => ArrowCursor.draw_highlight(display, area, menu_style),
+            HighlightStyle::Underline => Underline.draw_highlight(display, area, menu_style),
+        }
+    }
+
+    fn inverts_content(&self) -> bool {
+        match self {
+            HighlightStyle::FilledBar => HighlightRenderer::<C>::inverts_content(&FilledBar),
+            HighlightStyle::OutlineBox => HighlightRenderer::<C>::inverts_content(&OutlineBox),
+            HighlightStyle::RoundedBar => HighlightRenderer::<C>::inverts_content(&RoundedBar),
+            HighlightStyle::ArrowCursor => HighlightRenderer::<C>::inverts_content(&ArrowCursor),
+            HighlightStyle::Underline => HighlightRenderer::<C>::inverts_content(&Underline),
+        }
+    }
+
+    fn content_offset(&self, menu_style: &MenuStyle<'_, C>) -> u32 {
+        match self {
+            HighlightStyle::FilledBar => FilledBar.content_offset(menu_style),
+            HighlightStyle::OutlineBox => OutlineBox.content_offset(menu_style),
+            HighlightStyle::RoundedBar => RoundedBar.content_offset(menu_style),
+            HighlightStyle::ArrowCursor => ArrowCursor.content_offset(menu_style),
+            HighlightStyle::Underline => Underline.content_offset(menu_style),
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use crate::MenuStyle;
+use core::fmt;
+use core::fmt::{Debug, Formatter};
+use embedded_graphics::draw_target::DrawTarget;
+use embedded_graphics::geometry::{OriginDimensions, Point, Size};
+use embedded_graphics::image::GetPixel;
+use embedded_graphics::pixelcolor::PixelColor;
+use embedded_graphics::primitives::{PointsIter, Rectangle};
+use embedded_graphics::Pixel;
+
+/// Horizontal gap between an icon and the label drawn to its right
+const ICON_LABEL_GAP: u32 = 2;
+
+/// Image source usable as a menu item icon. Implemented for anything that can report its size
+/// and colour per pixel, such as `embedded_graphics::image::ImageRaw`.
+pub trait IconImage<C>: OriginDimensions + GetPixel<Color = C> {}
+
+impl<C, I> IconImage<C> for I
+where
+    C: PixelColor,
+    I: OriginDimensions + GetPixel<Color = C>,
+{
+}
+
+/// Icon drawn to the left of a menu item label
+#[derive(Clone, Copy)]
+pub struct MenuIcon<'a, C> {
+    image: &'a dyn IconImage<C>,
+}
+
+impl<'a, C> MenuIcon<'a, C>
+where
+    C: PixelColor,
+{
+    pub const fn new(image: &'a dyn IconImage<C>) -> Self {
+        Self { image }
+    }
+
+    pub fn size(&self) -> Size {
+        self.image.size()
+    }
+
+    /// Horizontal distance from the icon origin to where the item label starts
+    pub fn label_offset(&self) -> u32 {
+        self.size().width + ICON_LABEL_GAP
+    }
+
+    /// Draw the icon with its top-left corner at `position`, using the image colours as-is
+    pub(crate) fn draw<D>(&self, display: &mut D, position: Point) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.draw_mapped(display, position, |color| color)
+    }
+
+    /// Draw the icon on top of a highlight. Pixels in the item text colour are swapped for the
+    /// highlighted text colour and pixels in the menu background colour are swapped for the
+    /// highlight colour, which inverts monochrome (`BinaryColor`) icons with the default styles.
+    pub(crate) fn draw_highlighted<D>(
+        &self,
+        display: &mut D,
+        position: Point,
+        menu_style: &MenuStyle<'_, C>,
+    ) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let item_color = menu_style.item_character_style.text_color;
+        let highlight_text_color = menu_style.highlight_text_style.text_color;
+        self.draw_mapped(display, position, |color| {
+            if Some(color) == item_color {
+                highlight_text_color.unwrap_or(color)
+            } else if color == menu_style.menu_background_color {
+                menu_style.highlight_item_color
+            } else {
+                color
+            }
+        })
+    }
+
+    /// Draw the icon as part of an item, matching the colours used for the rest of its content
+    pub(crate) fn draw_in_item<D>(
+        &self,
+        display: &mut D,
+        position: Point,
+        highlighted: bool,
+        menu_style: &MenuStyle<'_, C>,
+    ) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        match menu_style.inverts_content(highlighted) {
+            true => self.draw_highlighted(display, position, menu_style),
+            false => self.draw(display, position),
+        }
+    }
+
+    fn draw_mapped<D, F>(
+        &self,
+        display: &mut D,
+        position: Point,
+        color_map: F,
+    ) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+        F: Fn(C) -> C,
+    {
+        let pixels = Rectangle::new(Point::zero(), self.size())
+            .points()
+            .filter_map(|p| {
+                self.image
+                    .pixel(p)
+                    .map(|color| Pixel(p + position, color_map(color)))
+            });
+        display.draw_iter(pixels)
+    }
+}
+
+impl<C> PartialEq for MenuIcon<'_, C> {
+    fn eq(&self, other: &Self) -> bool {
+        core::ptr::addr_eq(self.image, other.image)
+    }
+}
+
+impl<C> Debug for MenuIcon<'_, C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "[Icon]")
+    }
+}
+
+/// Position of an item label drawn at `position`, shifted right to make room for the icon
+pub(crate) fn label_position<C: PixelColor>(
+    icon: &Option<MenuIcon<'_, C>>,
+    position: Point,
+) -> Point {
+    match icon {
+        Some(icon) => position + Point::new(icon.label_offset() as i32, 0),
+        None => position,
+    }
+}
+
+/// Grow the bounds of an item label to include the icon drawn to its left
+pub(crate) fn bounds_with_icon<C: PixelColor>(
+    icon: &Option<MenuIcon<'_, C>>,
+    label_bounds: Rectangle,
+) -> Rectangle {
+    match icon {
+        Some(icon) => Rectangle::new(
+            label_bounds.top_left,
+            Size::new(
+                label_bounds.size.width + icon.label_offset(),
+                label_bounds.size.height.max(icon.size().height),
+            ),
+        ),
+        None => label_bounds,
+    }
+}
@@ -1,14 +1,16 @@
-use crate::items::{DrawableHighlighted, MenuItem, MenuItemData, SelectedData};
+use crate::icon::label_position;
+use crate::items::properties::ItemProperties;
+use crate::items::{
+    draw_description, draw_indicator, DrawableHighlighted, IndicatorDirection, MenuItem,
+    MenuItemData, SelectedData,
+};
 use crate::MenuStyle;
 use core::fmt;
 use core::fmt::{Debug, Display, Formatter};
 use embedded_graphics::draw_target::{DrawTarget, DrawTargetExt};
-use embedded_graphics::geometry::{AnchorX, Point, Size};
-use embedded_graphics::mono_font::MonoTextStyle;
+use embedded_graphics::geometry::Point;
 use embedded_graphics::pixelcolor::PixelColor;
-use embedded_graphics::prelude::Primitive;
-use embedded_graphics::primitives::{PrimitiveStyle, PrimitiveStyleBuilder, Rectangle, Triangle};
-use embedded_graphics::text::renderer::TextRenderer;
+use embedded_graphics::primitives::Rectangle;
 use embedded_graphics::text::{Baseline, Text};
 use embedded_graphics::Drawable;
 use embedded_layout::View;
@@ -20,9 +22,8 @@ where
     T: Clone + Copy + Sized,
 {
     label: &'static str,
-    highlighted: bool,
     position: Point,
-    menu_style: MenuStyle<'a, C>,
+    pub(crate) properties: ItemProperties<'a, C>,
     id: T,
 }
 
@@ -38,65 +39,59 @@ where
     ) -> ActionItem<'a, C, T> {
         ActionItem {
             label,
-            highlighted: false,
             position: Point::zero(),
-            menu_style,
+            properties: ItemProperties::new(menu_style),
             id,
         }
     }
 
-    fn draw_item<D>(
-        &self,
-        display: &mut D,
-        indicator_fill_color: C,
-        item_character_style: MonoTextStyle<'_, C>,
-    ) -> Result<(), D::Error>
+    fn draw_item<D>(&self, display: &mut D, highlighted: bool) -> Result<(), D::Error>
     where
         D: DrawTarget<Color = C>,
     {
-        let indicator_vertical_pad = 2u32;
-        let indicator_right_pad = 2u32;
-        let submenu_indicator_size = Size::new(self.size().height / 2, self.size().height);
-
-        let display_size = display.bounding_box();
-        let submenu_indicator_draw_area =
-            display_size.resized_width(submenu_indicator_size.width, AnchorX::Right);
-        let mut indicator_display = display.cropped(&submenu_indicator_draw_area);
-        let filled_style = PrimitiveStyle::with_fill(indicator_fill_color);
-
-        Triangle::new(
-            Point::new(0, indicator_vertical_pad as i32),
-            Point::new(
-                0,
-                (submenu_indicator_size.height - indicator_vertical_pad) as i32,
-            ),
-            Point::new(
-                (submenu_indicator_size.width - indicator_right_pad) as i32,
-                (((submenu_indicator_size.height - indicator_vertical_pad * 2) / 2)
-                    + indicator_vertical_pad) as i32,
-            ),
-        )
-        .into_styled(filled_style)
-        .draw(&mut indicator_display)?;
+        let menu_style = self.properties.style();
+        let item_character_style = menu_style.content_text_style(highlighted);
+        let content_position = menu_style.content_position(self.position);
+        if let Some(icon) = &self.properties.icon() {
+            icon.draw_in_item(display, content_position, highlighted, &menu_style)?;
+        }
 
-        let submenu_label_draw_area = display_size.resized_width(
-            display_size.size().width - submenu_indicator_size.width,
-            AnchorX::Left,
-        );
-        let mut label_display = display.cropped(&submenu_label_draw_area);
+        let first_line_height = self.properties.first_line_bounds(self.label).size.height;
+        let label_area = draw_indicator(
+            display,
+            IndicatorDirection::Right,
+            first_line_height,
+            menu_style.content_indicator_color(highlighted),
+        )?;
+        let mut label_display = display.cropped(&label_area);
 
+        let label_position = label_position(&self.properties.icon(), content_position);
         Text::with_baseline(
             self.label,
-            self.position,
+            label_position,
             item_character_style,
             Baseline::Top,
         )
         .draw(&mut label_display)?;
+        draw_description(
+            &mut label_display,
+            self.properties.description(),
+            label_position + Point::new(0, first_line_height as i32),
+            highlighted,
+            &menu_style,
+        )?;
 
         Ok(())
     }
 }
 
+impl<'a, C, T> ActionItem<'a, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+}
+
 impl<C, T> MenuItem<T> for ActionItem<'_, C, T>
 where
     C: PixelColor,
@@ -155,10 +150,7 @@ where
     }
 
     fn bounds(&self) -> Rectangle {
-        self.menu_style
-            .item_character_style
-            .measure_string(self.label, Point::zero(), Baseline::Bottom)
-            .bounding_box
+        self.properties.bounds(self.label)
     }
 }
 
@@ -174,12 +166,7 @@ where
     where
         D: DrawTarget<Color = Self::Color>,
     {
-        self.draw_item(
-            display,
-            self.menu_style.indicator_fill_color,
-            self.menu_style.item_character_style,
-        )?;
-        Ok(())
+        self.draw_item(display, false)
     }
 }
 
@@ -194,25 +181,9 @@ where
     where
         D: DrawTarget<Color = Self::Color>,
     {
-        let highlight_box_style = PrimitiveStyleBuilder::new()
-            .fill_color(self.menu_style.highlight_item_color)
-            .build();
-
-        Rectangle::new(
-            self.position,
-            Size::new(
-                display.bounding_box().size().width,
-                self.menu_style.highlight_text_style.line_height(),
-            ),
-        )
-        .into_styled(highlight_box_style)
-        .draw(display)?;
-
-        self.draw_item(
-            display,
-            self.menu_style.highlight_indicator_fill_color,
-            self.menu_style.highlight_text_style,
-        )?;
-        Ok(())
+        self.properties
+            .style()
+            .draw_highlight(display, self.position, self.size().height)?;
+        self.draw_item(display, true)
     }
 }
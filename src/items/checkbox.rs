@@ -1,4 +1,8 @@
-use crate::items::{DrawableHighlighted, MenuItem, MenuItemData, SelectedData};
+use crate::icon::label_position;
+use crate::items::properties::ItemProperties;
+use crate::items::{
+    text_width, DrawableHighlighted, MenuItem, MenuItemData, SelectedData, MIN_DRAWN_FONT_HEIGHT,
+};
 use crate::MenuStyle;
 use core::fmt;
 use core::fmt::{Debug, Display, Formatter};
@@ -6,12 +10,29 @@ use embedded_graphics::draw_target::DrawTarget;
 use embedded_graphics::geometry::{Point, Size};
 use embedded_graphics::pixelcolor::PixelColor;
 use embedded_graphics::prelude::Primitive;
-use embedded_graphics::primitives::{PrimitiveStyleBuilder, Rectangle};
-use embedded_graphics::text::renderer::TextRenderer;
+use embedded_graphics::primitives::{Circle, PrimitiveStyle, Rectangle, RoundedRectangle};
 use embedded_graphics::text::{Alignment, Baseline, Text, TextStyleBuilder};
 use embedded_graphics::Drawable;
 use embedded_layout::View;
 
+/// How the state of a checkbox is shown on the right of the item
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CheckboxStyle {
+    /// "[X]" and "[ ]"
+    Ascii,
+    /// Outlined square, filled in when checked
+    Box,
+    /// Rounded switch with the knob on the right when checked
+    Toggle,
+    /// "ON" and "OFF"
+    OnOff,
+    /// Text of your own for the checked and unchecked states
+    Text {
+        checked: &'static str,
+        unchecked: &'static str,
+    },
+}
+
 #[derive(PartialEq, Clone, Copy)]
 pub struct CheckboxItem<'a, C, T>
 where
@@ -20,7 +41,8 @@ where
 {
     label: &'static str,
     position: Point,
-    menu_style: MenuStyle<'a, C>,
+    pub(crate) properties: ItemProperties<'a, C>,
+    checkbox_style: Option<CheckboxStyle>,
     checkbox_state: bool,
     id: T,
 }
@@ -39,11 +61,158 @@ where
         CheckboxItem {
             label,
             position: Point::zero(),
-            menu_style,
+            properties: ItemProperties::new(menu_style),
+            checkbox_style: None,
             checkbox_state: initial_state,
             id,
         }
     }
+
+    fn draw_item<D>(&self, display: &mut D, highlighted: bool) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let menu_style = self.properties.style();
+        let item_character_style = menu_style.content_text_style(highlighted);
+        let content_position = menu_style.content_position(self.position);
+        if let Some(icon) = &self.properties.icon() {
+            icon.draw_in_item(display, content_position, highlighted, &menu_style)?;
+        }
+
+        let label_position = label_position(&self.properties.icon(), content_position);
+        Text::with_baseline(
+            self.label,
+            label_position,
+            item_character_style,
+            Baseline::Top,
+        )
+        .draw(display)?;
+        self.properties
+            .draw_description(display, self.label, label_position, highlighted)?;
+
+        let width = display.bounding_box().size().width;
+        let value_top = match self.value_wraps(width) {
+            true => self.size().height as i32,
+            false => 0,
+        };
+        let value_style = menu_style.content_value_style(highlighted);
+        let glyph_size = value_style.font.character_size.height.saturating_sub(2);
+        let glyph_top = value_top + 1;
+        let (Some(value_color), CheckboxStyle::Box | CheckboxStyle::Toggle) =
+            (value_style.text_color, self.rendering())
+        else {
+            Text::with_text_style(
+                self.display_string(),
+                Point::new(width as i32, value_top),
+                value_style,
+                TextStyleBuilder::new()
+                    .alignment(Alignment::Right)
+                    .baseline(Baseline::Top)
+                    .build(),
+            )
+            .draw(display)?;
+            return Ok(());
+        };
+
+        let glyph_left = width as i32 - self.value_width() as i32;
+        let background_color = menu_style.content_background_color(highlighted);
+        if self.rendering() == CheckboxStyle::Box {
+            let glyph_area = Rectangle::new(
+                Point::new(glyph_left, glyph_top),
+                Size::new(glyph_size, glyph_size),
+            );
+            glyph_area
+                .into_styled(PrimitiveStyle::with_stroke(value_color, 1))
+                .draw(display)?;
+            if self.checkbox_state {
+                glyph_area
+                    .offset(-2)
+                    .into_styled(PrimitiveStyle::with_fill(value_color))
+                    .draw(display)?;
+            }
+        } else {
+            let track_area = Rectangle::new(
+                Point::new(glyph_left, glyph_top),
+                Size::new(glyph_size * 2, glyph_size),
+            );
+            let corner_radius = Size::new(glyph_size / 2, glyph_size / 2);
+            let track_style = match self.checkbox_state {
+                true => PrimitiveStyle::with_fill(value_color),
+                false => PrimitiveStyle::with_stroke(value_color, 1),
+            };
+            RoundedRectangle::with_equal_corners(track_area, corner_radius)
+                .into_styled(track_style)
+                .draw(display)?;
+
+            // The knob sits inside the track, on the right when checked
+            let knob_diameter = glyph_size.saturating_sub(4);
+            let knob_left = match self.checkbox_state {
+                true => glyph_left + (glyph_size * 2 - 2 - knob_diameter) as i32,
+                false => glyph_left + 2,
+            };
+            let knob_color = match self.checkbox_state {
+                true => background_color,
+                false => value_color,
+            };
+            Circle::new(Point::new(knob_left, glyph_top + 2), knob_diameter)
+                .into_styled(PrimitiveStyle::with_fill(knob_color))
+                .draw(display)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, C, T> CheckboxItem<'a, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    /// Change how the checkbox state is drawn for this item only, or go back to the menu style
+    /// with `None`
+    pub fn set_checkbox_style(&mut self, checkbox_style: Option<CheckboxStyle>) {
+        self.checkbox_style = checkbox_style;
+    }
+
+    pub fn checkbox_style(&self) -> Option<CheckboxStyle> {
+        self.checkbox_style
+    }
+
+    /// The checkbox style to draw with, after falling back to ASCII for fonts too small to draw
+    /// the box or toggle legibly
+    fn rendering(&self) -> CheckboxStyle {
+        let menu_style = self.properties.style();
+        match self.checkbox_style.unwrap_or(menu_style.checkbox_style) {
+            CheckboxStyle::Box | CheckboxStyle::Toggle
+                if menu_style.value_character_style.font.character_size.height
+                    < MIN_DRAWN_FONT_HEIGHT =>
+            {
+                CheckboxStyle::Ascii
+            }
+            checkbox_style => checkbox_style,
+        }
+    }
+
+    fn value_width(&self) -> u32 {
+        let value_style = self.properties.style().value_character_style;
+        let glyph_size = value_style.font.character_size.height.saturating_sub(2);
+        match self.rendering() {
+            CheckboxStyle::Box => glyph_size,
+            CheckboxStyle::Toggle => glyph_size * 2,
+            _ => text_width(&value_style, self.display_string()),
+        }
+    }
+
+    /// Height of the item when drawn `width` wide, with a second line for the value if it doesn't
+    /// fit beside the label
+    pub fn height_in(&self, width: u32) -> u32 {
+        self.properties.height(self.label, self.value_wraps(width))
+    }
+
+    fn value_wraps(&self, width: u32) -> bool {
+        self.properties
+            .value_wraps(self.label, self.position, self.value_width(), width)
+    }
 }
 
 impl<C, T> MenuItem<T> for CheckboxItem<'_, C, T>
@@ -87,10 +256,7 @@ where
     }
 
     fn bounds(&self) -> Rectangle {
-        self.menu_style
-            .item_character_style
-            .measure_string(self.label, Point::zero(), Baseline::Bottom)
-            .bounding_box
+        self.properties.bounds(self.label)
     }
 }
 
@@ -105,26 +271,7 @@ where
     where
         D: DrawTarget<Color = Self::Color>,
     {
-        Text::with_baseline(
-            self.label,
-            self.position,
-            self.menu_style.item_character_style,
-            Baseline::Top,
-        )
-        .draw(display)?;
-
-        Text::with_text_style(
-            self.display_string(),
-            Point::new(display.bounding_box().size().width as i32, 0),
-            self.menu_style.item_character_style,
-            TextStyleBuilder::new()
-                .alignment(Alignment::Right)
-                .baseline(Baseline::Top)
-                .build(),
-        )
-        .draw(display)?;
-
-        Ok(())
+        self.draw_item(display, false)
     }
 }
 
@@ -139,40 +286,12 @@ where
     where
         D: DrawTarget<Color = Self::Color>,
     {
-        let highlight_box_style = PrimitiveStyleBuilder::new()
-            .fill_color(self.menu_style.highlight_item_color)
-            .build();
-
-        Rectangle::new(
-            self.position,
-            Size::new(
-                display.bounding_box().size().width,
-                self.menu_style.highlight_text_style.line_height(),
-            ),
-        )
-        .into_styled(highlight_box_style)
-        .draw(display)?;
-
-        Text::with_baseline(
-            self.label,
+        self.properties.style().draw_highlight(
+            display,
             self.position,
-            self.menu_style.highlight_text_style,
-            Baseline::Top,
-        )
-        .draw(display)?;
-
-        Text::with_text_style(
-            self.display_string(),
-            Point::new(display.bounding_box().size().width as i32, 0),
-            self.menu_style.highlight_text_style,
-            TextStyleBuilder::new()
-                .alignment(Alignment::Right)
-                .baseline(Baseline::Top)
-                .build(),
-        )
-        .draw(display)?;
-
-        Ok(())
+            self.height_in(display.bounding_box().size().width),
+        )?;
+        self.draw_item(display, true)
     }
 }
 
@@ -190,9 +309,14 @@ where
     }
 
     fn display_string(&self) -> &str {
-        match self.checkbox_state {
-            true => "[X]",
-            false => "[ ]",
+        match (self.rendering(), self.checkbox_state) {
+            (CheckboxStyle::OnOff, true) => "ON",
+            (CheckboxStyle::OnOff, false) => "OFF",
+            (CheckboxStyle::Text { checked, .. }, true) => checked,
+            (CheckboxStyle::Text { unchecked, .. }, false) => unchecked,
+            // The drawn styles have the ASCII form as their text
+            (_, true) => "[X]",
+            (_, false) => "[ ]",
         }
     }
 }
@@ -0,0 +1,541 @@
+use crate::footer::SoftKeyHints;
+use crate::items::properties::ItemProperties;
+use crate::items::{DrawableHighlighted, EditAction, MenuItem, MenuItemData, SelectedData};
+use crate::{MenuStyle, StyleOverride};
+use core::convert::Infallible;
+use core::fmt;
+use core::fmt::{Debug, Display, Formatter};
+use core::marker::PhantomData;
+use embedded_graphics::draw_target::DrawTarget;
+use embedded_graphics::geometry::Point;
+use embedded_graphics::pixelcolor::PixelColor;
+use embedded_graphics::primitives::Rectangle;
+use embedded_graphics::Drawable;
+use embedded_layout::View;
+
+/// An item kind of the application's own, navigated and drawn like the built in ones. Implement
+/// [`MenuItem`], [`MenuItemData`], [`DrawableHighlighted`], [`Drawable`] and [`View`] for it,
+/// then add it with [`Menu::add_custom`](crate::Menu::add_custom) to a menu made with
+/// [`Menu::with_custom_items`](crate::Menu::with_custom_items) for it, like a
+/// `Menu<'a, C, T, Gauge>`. Applications with several kinds of their own wrap them in an enum.
+///
+/// The menu draws the highlight behind the item, which draws itself on top in either case.
+pub trait CustomMenuItem<'a, C, T>:
+    MenuItem<T>
+    + Drawable<Color = C, Output = ()>
+    + DrawableHighlighted<Color = C, Output = ()>
+    + Copy
+    + PartialEq
+    + Debug
+where
+    C: PixelColor,
+{
+    /// Take on the menu style with the override set for the item applied, when the item is added
+    /// and whenever either changes. The `content_*` methods of the style keep text readable on
+    /// the highlight.
+    fn set_menu_style(&mut self, menu_style: MenuStyle<'a, C>) {
+        let _ = menu_style;
+    }
+
+    /// While this is true after [`selected`](MenuItemData::selected), navigation goes to
+    /// [`edit`](Self::edit) instead of moving the highlight
+    fn is_editing(&self) -> bool {
+        false
+    }
+
+    /// Apply a navigation key while editing, returning a change to report straight away if there
+    /// is one
+    fn edit(&mut self, action: EditAction) -> Option<SelectedData<T>> {
+        let _ = action;
+        None
+    }
+
+    /// Navigation passes over items that can't be highlighted, as it does section headings
+    fn is_highlightable(&self) -> bool {
+        true
+    }
+
+    /// Footer hints shown while the item is highlighted, unless the item has its own
+    fn soft_key_hints(&self) -> SoftKeyHints {
+        SoftKeyHints::new("Back", "Select", "")
+    }
+}
+
+/// Item type of menus without items of the application's own, which can't be made
+pub struct NoCustomItems<C>(Infallible, PhantomData<C>);
+
+impl<C> Clone for NoCustomItems<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C> Copy for NoCustomItems<C> {}
+
+impl<C> PartialEq for NoCustomItems<C> {
+    fn eq(&self, _: &Self) -> bool {
+        match self.0 {}
+    }
+}
+
+impl<C> Debug for NoCustomItems<C> {
+    fn fmt(&self, _: &mut Formatter<'_>) -> fmt::Result {
+        match self.0 {}
+    }
+}
+
+impl<C> Display for NoCustomItems<C> {
+    fn fmt(&self, _: &mut Formatter<'_>) -> fmt::Result {
+        match self.0 {}
+    }
+}
+
+impl<C> View for NoCustomItems<C> {
+    fn translate_impl(&mut self, _: Point) {
+        match self.0 {}
+    }
+
+    fn bounds(&self) -> Rectangle {
+        match self.0 {}
+    }
+}
+
+impl<C: PixelColor> Drawable for NoCustomItems<C> {
+    type Color = C;
+    type Output = ();
+
+    fn draw<D>(&self, _: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        match self.0 {}
+    }
+}
+
+impl<C: PixelColor> DrawableHighlighted for NoCustomItems<C> {
+    type Color = C;
+    type Output = ();
+
+    fn draw_highlighted<D>(&self, _: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        match self.0 {}
+    }
+}
+
+impl<C: PixelColor, T> MenuItemData<T> for NoCustomItems<C> {
+    fn selected(&mut self) -> SelectedData<T> {
+        match self.0 {}
+    }
+
+    fn display_string(&self) -> &str {
+        match self.0 {}
+    }
+}
+
+impl<C: PixelColor, T> MenuItem<T> for NoCustomItems<C> {
+    fn label(&self) -> &'static str {
+        match self.0 {}
+    }
+
+    fn id(&self) -> T {
+        match self.0 {}
+    }
+}
+
+impl<C: PixelColor, T> CustomMenuItem<'_, C, T> for NoCustomItems<C> {}
+
+/// Row showing a [`CustomMenuItem`], with the help text and soft keys every item has
+#[derive(PartialEq, Clone, Copy)]
+pub struct CustomItem<'a, C, X>
+where
+    C: PixelColor,
+{
+    pub(crate) properties: ItemProperties<'a, C>,
+    item: X,
+}
+
+impl<'a, C, X> CustomItem<'a, C, X>
+where
+    C: PixelColor,
+{
+    pub fn new<T>(menu_style: MenuStyle<'a, C>, mut item: X) -> Self
+    where
+        X: CustomMenuItem<'a, C, T>,
+    {
+        item.set_menu_style(menu_style);
+        CustomItem {
+            properties: ItemProperties::new(menu_style),
+            item,
+        }
+    }
+
+    pub fn item(&self) -> &X {
+        &self.item
+    }
+
+    pub(crate) fn set_menu_style<T>(&mut self, menu_style: MenuStyle<'a, C>)
+    where
+        X: CustomMenuItem<'a, C, T>,
+    {
+        self.properties.set_menu_style(menu_style);
+        self.item.set_menu_style(self.properties.style());
+    }
+
+    pub(crate) fn set_style_override<T>(&mut self, style_override: Option<StyleOverride<'a, C>>)
+    where
+        X: CustomMenuItem<'a, C, T>,
+    {
+        self.properties.set_style_override(style_override);
+        self.item.set_menu_style(self.properties.style());
+    }
+
+    pub fn edit<T>(&mut self, action: EditAction) -> Option<SelectedData<T>>
+    where
+        X: CustomMenuItem<'a, C, T>,
+    {
+        self.item.edit(action).map(|edited| self.reported(edited))
+    }
+
+    /// What the item reports, with the selections the menu makes and acts on itself, such as
+    /// opening a submenu, reported as an action of the item instead
+    fn reported<T>(&self, selected: SelectedData<T>) -> SelectedData<T>
+    where
+        X: CustomMenuItem<'a, C, T>,
+    {
+        match selected {
+            SelectedData::Submenu { .. }
+            | SelectedData::Back { .. }
+            | SelectedData::ListEntry { .. }
+            | SelectedData::TooDeep { .. }
+            | SelectedData::AccessDenied { .. } => SelectedData::Action { id: self.item.id() },
+            selected => selected,
+        }
+    }
+}
+
+impl<'a, C, T, X> MenuItem<T> for CustomItem<'a, C, X>
+where
+    C: PixelColor,
+    X: CustomMenuItem<'a, C, T>,
+{
+    fn label(&self) -> &'static str {
+        self.item.label()
+    }
+
+    fn id(&self) -> T {
+        self.item.id()
+    }
+}
+
+impl<'a, C, T, X> MenuItemData<T> for CustomItem<'a, C, X>
+where
+    C: PixelColor,
+    X: CustomMenuItem<'a, C, T>,
+{
+    fn selected(&mut self) -> SelectedData<T> {
+        let selected = self.item.selected();
+        self.reported(selected)
+    }
+
+    fn display_string(&self) -> &str {
+        self.item.display_string()
+    }
+}
+
+impl<C, X> Debug for CustomItem<'_, C, X>
+where
+    C: PixelColor,
+    X: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.item, f)
+    }
+}
+
+impl<C, X> Display for CustomItem<'_, C, X>
+where
+    C: PixelColor,
+    X: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.item, f)
+    }
+}
+
+impl<C, X> View for CustomItem<'_, C, X>
+where
+    C: PixelColor,
+    X: View,
+{
+    fn translate_impl(&mut self, by: Point) {
+        self.item.translate_impl(by);
+    }
+
+    fn bounds(&self) -> Rectangle {
+        self.item.bounds()
+    }
+}
+
+impl<C, X> Drawable for CustomItem<'_, C, X>
+where
+    C: PixelColor,
+    X: Drawable<Color = C, Output = ()>,
+{
+    type Color = C;
+    type Output = ();
+
+    fn draw<D>(&self, display: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        self.item.draw(display)
+    }
+}
+
+impl<C, X> DrawableHighlighted for CustomItem<'_, C, X>
+where
+    C: PixelColor,
+    X: View + DrawableHighlighted<Color = C, Output = ()>,
+{
+    type Color = C;
+    type Output = ();
+
+    fn draw_highlighted<D>(&self, display: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        let bounds = self.item.bounds();
+        self.properties
+            .style()
+            .draw_highlight(display, bounds.top_left, bounds.size.height)?;
+        self.item.draw_highlighted(display)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::MenuTheme;
+    use crate::{Menu, MenuStyleBuilder};
+    use embedded_graphics::geometry::Size;
+    use embedded_graphics::mock_display::MockDisplay;
+    use embedded_graphics::mono_font::ascii::FONT_6X10;
+    use embedded_graphics::pixelcolor::BinaryColor;
+    use embedded_graphics::prelude::Primitive;
+    use embedded_graphics::primitives::PrimitiveStyle;
+
+    const GAUGE_ID: u32 = 2;
+    const MAX_LEVEL: u32 = 40;
+    const BAR_HEIGHT: u32 = 8;
+
+    /// Bar `level` pixels long, changed with the navigation keys once selected
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Gauge {
+        level: u32,
+        editing: bool,
+        reports_back: bool,
+        position: Point,
+        menu_style: Option<MenuStyle<'static, BinaryColor>>,
+    }
+
+    impl Gauge {
+        const fn new(level: u32) -> Self {
+            Self {
+                level,
+                editing: false,
+                reports_back: false,
+                position: Point::zero(),
+                menu_style: None,
+            }
+        }
+
+        fn draw_bar<D>(&self, display: &mut D, highlighted: bool) -> Result<(), D::Error>
+        where
+            D: DrawTarget<Color = BinaryColor>,
+        {
+            let Some(menu_style) = self.menu_style else {
+                return Ok(());
+            };
+            Rectangle::new(self.position, Size::new(self.level, BAR_HEIGHT))
+                .into_styled(PrimitiveStyle::with_fill(
+                    menu_style.content_indicator_color(highlighted),
+                ))
+                .draw(display)
+        }
+    }
+
+    impl View for Gauge {
+        fn translate_impl(&mut self, by: Point) {
+            self.position += by;
+        }
+
+        fn bounds(&self) -> Rectangle {
+            Rectangle::new(self.position, Size::new(MAX_LEVEL, BAR_HEIGHT))
+        }
+    }
+
+    impl Drawable for Gauge {
+        type Color = BinaryColor;
+        type Output = ();
+
+        fn draw<D>(&self, display: &mut D) -> Result<Self::Output, D::Error>
+        where
+            D: DrawTarget<Color = Self::Color>,
+        {
+            self.draw_bar(display, false)
+        }
+    }
+
+    impl DrawableHighlighted for Gauge {
+        type Color = BinaryColor;
+        type Output = ();
+
+        fn draw_highlighted<D>(&self, display: &mut D) -> Result<Self::Output, D::Error>
+        where
+            D: DrawTarget<Color = Self::Color>,
+        {
+            self.draw_bar(display, true)
+        }
+    }
+
+    impl Display for Gauge {
+        fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            write!(f, "Gauge")
+        }
+    }
+
+    impl MenuItemData<u32> for Gauge {
+        fn selected(&mut self) -> SelectedData<u32> {
+            if self.reports_back {
+                return SelectedData::Back { id: GAUGE_ID };
+            }
+            self.editing = !self.editing;
+            SelectedData::Slider {
+                id: GAUGE_ID,
+                value: self.level as i32,
+            }
+        }
+
+        fn display_string(&self) -> &str {
+            "Gauge"
+        }
+    }
+
+    impl MenuItem<u32> for Gauge {
+        fn label(&self) -> &'static str {
+            "Gauge"
+        }
+
+        fn id(&self) -> u32 {
+            GAUGE_ID
+        }
+    }
+
+    impl CustomMenuItem<'static, BinaryColor, u32> for Gauge {
+        fn set_menu_style(&mut self, menu_style: MenuStyle<'static, BinaryColor>) {
+            self.menu_style = Some(menu_style);
+        }
+
+        fn is_editing(&self) -> bool {
+            self.editing
+        }
+
+        fn edit(&mut self, action: EditAction) -> Option<SelectedData<u32>> {
+            self.level = match action {
+                EditAction::Increase | EditAction::Next => (self.level + 1).min(MAX_LEVEL),
+                EditAction::Decrease | EditAction::Previous => self.level.saturating_sub(1),
+            };
+            Some(SelectedData::Slider {
+                id: GAUGE_ID,
+                value: self.level as i32,
+            })
+        }
+    }
+
+    fn style() -> MenuStyle<'static, BinaryColor> {
+        MenuStyleBuilder::new(&FONT_6X10, &FONT_6X10, MenuTheme::dark()).build()
+    }
+
+    /// Menu with an action above `gauge`, which is highlighted
+    fn menu_with(gauge: Gauge) -> Menu<'static, BinaryColor, u32, Gauge> {
+        let mut menu = Menu::with_custom_items("Menu", 0, style());
+        menu.add_action("First", 1);
+        menu.add_custom(gauge);
+        menu.navigate_down();
+        menu
+    }
+
+    #[test]
+    fn custom_items_are_edited_like_built_in_ones() {
+        let mut menu = menu_with(Gauge::new(4));
+        assert_eq!(menu.select_item(), None);
+        assert!(menu.is_editing());
+        menu.navigate_up();
+        menu.navigate_up();
+        menu.navigate_down();
+        assert_eq!(
+            menu.take_edit_change(),
+            Some(SelectedData::Slider {
+                id: GAUGE_ID,
+                value: 5
+            })
+        );
+        assert_eq!(
+            menu.select_item(),
+            Some(SelectedData::Slider {
+                id: GAUGE_ID,
+                value: 5
+            })
+        );
+        assert!(!menu.is_editing());
+    }
+
+    #[test]
+    fn navigation_reported_by_custom_items_is_left_to_the_menu() {
+        let mut gauge = Gauge::new(4);
+        gauge.reports_back = true;
+        let mut menu = Menu::with_custom_items("Menu", 0, style());
+        let mut submenu = Menu::with_custom_items("Submenu", 10, style());
+        submenu.add_custom(gauge);
+        menu.add_submenu(submenu);
+        assert_eq!(menu.select_item(), Some(SelectedData::Submenu { id: 10 }));
+        assert_eq!(
+            menu.select_item(),
+            Some(SelectedData::Action { id: GAUGE_ID })
+        );
+        // Still in the submenu, which would have been entered again otherwise
+        assert_eq!(
+            menu.select_item(),
+            Some(SelectedData::Action { id: GAUGE_ID })
+        );
+    }
+
+    #[test]
+    fn custom_items_draw_over_the_menu_highlight() {
+        let item = CustomItem::new(style(), Gauge::new(4));
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        item.draw_highlighted(&mut display).unwrap();
+        assert_eq!(
+            display.get_pixel(Point::zero()),
+            Some(style().content_indicator_color(true))
+        );
+        assert_eq!(
+            display.get_pixel(Point::new(30, 1)),
+            Some(style().highlight_item_color)
+        );
+
+        let mut display = MockDisplay::new();
+        item.draw(&mut display).unwrap();
+        assert_eq!(
+            display.affected_area(),
+            Rectangle::new(Point::zero(), Size::new(4, BAR_HEIGHT))
+        );
+        assert_eq!(
+            display.get_pixel(Point::zero()),
+            Some(style().content_indicator_color(false))
+        );
+    }
+}
@@ -0,0 +1,434 @@
+use crate::icon::label_position;
+use crate::items::field_editor::{draw_fields, wheel_step, FieldCursor};
+use crate::items::properties::ItemProperties;
+use crate::items::{
+    text_width, DrawableHighlighted, EditAction, MenuItem, MenuItemData, SelectedData,
+};
+use crate::text_buffer::TextBuffer;
+use crate::MenuStyle;
+use core::fmt;
+use core::fmt::{Debug, Display, Formatter};
+use embedded_graphics::draw_target::DrawTarget;
+use embedded_graphics::geometry::Point;
+use embedded_graphics::pixelcolor::PixelColor;
+use embedded_graphics::primitives::Rectangle;
+use embedded_graphics::text::{Baseline, Text};
+use embedded_graphics::Drawable;
+use embedded_layout::View;
+
+/// Calendar date, always a day that exists
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Date {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    /// The date, or `None` if the month or day is out of range for that year
+    pub const fn new(year: u16, month: u8, day: u8) -> Option<Self> {
+        if month >= 1 && month <= 12 && day >= 1 && day <= days_in_month(year, month) {
+            Some(Self { year, month, day })
+        } else {
+            None
+        }
+    }
+
+    pub const fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// Month of the year, 1 to 12
+    pub const fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Day of the month, from 1
+    pub const fn day(&self) -> u8 {
+        self.day
+    }
+}
+
+/// Gregorian leap years: every fourth year, except centuries not divisible by 400
+// is_multiple_of would need Rust 1.87
+#[allow(clippy::manual_is_multiple_of)]
+pub const fn is_leap_year(year: u16) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// Days in `month` (1 to 12) of `year`
+pub const fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Default years the editor steps through
+const MIN_YEAR: u16 = 2000;
+const MAX_YEAR: u16 = 2099;
+
+/// Year, month and day
+const FIELD_COUNT: usize = 3;
+
+/// Longest value text, "65535-12-31"
+const VALUE_TEXT_LEN: usize = 11;
+
+/// Editor for a calendar date, shown as "YYYY-MM-DD". Selecting the item starts editing the
+/// year; up and down change the field, left and right move between fields, and selecting steps
+/// on to the next field, finishing after the day. The day is kept within the length of the
+/// month as the year and month change.
+#[derive(PartialEq, Clone, Copy)]
+pub struct DateItem<'a, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    label: &'static str,
+    position: Point,
+    pub(crate) properties: ItemProperties<'a, C>,
+    date: Date,
+    years: (u16, u16),
+    value_text: TextBuffer<VALUE_TEXT_LEN>,
+    cursor: FieldCursor,
+    id: T,
+}
+
+impl<C, T> DateItem<'_, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    pub fn new<'a>(
+        label: &'static str,
+        id: T,
+        menu_style: MenuStyle<'a, C>,
+        date: Date,
+    ) -> DateItem<'a, C, T> {
+        let mut item = DateItem {
+            label,
+            position: Point::zero(),
+            properties: ItemProperties::new(menu_style),
+            date,
+            years: (MIN_YEAR, MAX_YEAR),
+            value_text: TextBuffer::new(),
+            cursor: FieldCursor::new(),
+            id,
+        };
+        item.update_value_text();
+        item
+    }
+
+    fn draw_item<D>(&self, display: &mut D, highlighted: bool) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let menu_style = self.properties.style();
+        let item_character_style = menu_style.content_text_style(highlighted);
+        let content_position = menu_style.content_position(self.position);
+        if let Some(icon) = &self.properties.icon() {
+            icon.draw_in_item(display, content_position, highlighted, &menu_style)?;
+        }
+
+        let label_position = label_position(&self.properties.icon(), content_position);
+        Text::with_baseline(
+            self.label,
+            label_position,
+            item_character_style,
+            Baseline::Top,
+        )
+        .draw(display)?;
+        self.properties
+            .draw_description(display, self.label, label_position, highlighted)?;
+
+        let active_field = match self.cursor.field() {
+            Some(0) => Some(0..4),
+            Some(1) => Some(5..7),
+            Some(2) => Some(8..10),
+            _ => None,
+        };
+        let value_top = match self.value_wraps(display.bounding_box().size().width) {
+            true => self.size().height as i32,
+            false => 0,
+        };
+        draw_fields(
+            display,
+            value_top,
+            self.display_string(),
+            active_field,
+            highlighted,
+            &menu_style,
+        )
+    }
+}
+
+impl<'a, C, T> DateItem<'a, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    /// Show `date`, with the year moved into the range set with
+    /// [`set_year_range`](Self::set_year_range) if it is outside it
+    pub fn set_date(&mut self, date: Date) {
+        self.date = date;
+        self.keep_in_year_range();
+    }
+
+    pub fn date(&self) -> Date {
+        self.date
+    }
+
+    /// Limit the years the editor steps through, 2000 to 2099 by default to suit common RTC chips
+    pub fn set_year_range(&mut self, min_year: u16, max_year: u16) {
+        self.years = (min_year.min(max_year), max_year.max(min_year));
+        self.keep_in_year_range();
+    }
+
+    fn keep_in_year_range(&mut self) {
+        self.date.year = self.date.year.clamp(self.years.0, self.years.1);
+        // Moving out of a leap year can leave the day past the end of February
+        self.date.day = self
+            .date
+            .day
+            .min(days_in_month(self.date.year, self.date.month));
+        self.update_value_text();
+    }
+
+    pub fn is_editing(&self) -> bool {
+        self.cursor.is_editing()
+    }
+
+    /// Change the field under the cursor or move the cursor. Dates are only reported once
+    /// editing finishes.
+    pub fn edit(&mut self, action: EditAction) -> Option<SelectedData<T>> {
+        let (min_year, max_year) = self.years;
+        let date = &mut self.date;
+        match self.cursor.apply(action, FIELD_COUNT) {
+            Some(0) => {
+                date.year =
+                    wheel_step(date.year as u32, min_year as u32, max_year as u32, action) as u16
+            }
+            Some(1) => date.month = wheel_step(date.month as u32, 1, 12, action) as u8,
+            Some(2) => {
+                let month_length = days_in_month(date.year, date.month) as u32;
+                date.day = wheel_step(date.day as u32, 1, month_length, action) as u8;
+            }
+            _ => {}
+        }
+        // Moving from a long month or a leap year can leave the day past the end of the month
+        date.day = date.day.min(days_in_month(date.year, date.month));
+        self.update_value_text();
+        None
+    }
+
+    fn update_value_text(&mut self) {
+        let Date { year, month, day } = self.date;
+        self.value_text = TextBuffer::from_fmt(format_args!("{:04}-{:02}-{:02}", year, month, day));
+    }
+
+    /// Height of the item when drawn `width` wide, with a second line for the value if it doesn't
+    /// fit beside the label
+    pub fn height_in(&self, width: u32) -> u32 {
+        self.properties.height(self.label, self.value_wraps(width))
+    }
+
+    fn value_wraps(&self, width: u32) -> bool {
+        self.properties.value_wraps(
+            self.label,
+            self.position,
+            text_width(
+                &self.properties.style().value_character_style,
+                self.display_string(),
+            ),
+            width,
+        )
+    }
+}
+
+impl<C, T> MenuItem<T> for DateItem<'_, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    fn label(&self) -> &'static str {
+        self.label
+    }
+    fn id(&self) -> T {
+        self.id
+    }
+}
+
+impl<C: PixelColor, T> Debug for DateItem<'_, C, T>
+where
+    T: Clone + Copy + Sized,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "[\"{}\":Date]", self.label)
+    }
+}
+
+impl<C: PixelColor, T> Display for DateItem<'_, C, T>
+where
+    T: Clone + Copy + Sized,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.label)
+    }
+}
+
+impl<C: PixelColor, T> View for DateItem<'_, C, T>
+where
+    T: Clone + Copy + Sized,
+{
+    fn translate_impl(&mut self, by: Point) {
+        self.position += by;
+    }
+
+    fn bounds(&self) -> Rectangle {
+        self.properties.bounds(self.label)
+    }
+}
+
+impl<C: PixelColor, T> Drawable for DateItem<'_, C, T>
+where
+    T: Clone + Copy + Sized,
+{
+    type Color = C;
+    type Output = ();
+
+    fn draw<D>(&self, display: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        self.draw_item(display, false)
+    }
+}
+
+impl<C: PixelColor, T> DrawableHighlighted for DateItem<'_, C, T>
+where
+    T: Clone + Copy + Sized,
+{
+    type Color = C;
+    type Output = ();
+
+    fn draw_highlighted<D>(&self, display: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        self.properties.style().draw_highlight(
+            display,
+            self.position,
+            self.height_in(display.bounding_box().size().width),
+        )?;
+        self.draw_item(display, true)
+    }
+}
+
+impl<C, T> MenuItemData<T> for DateItem<'_, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    /// Start editing or step to the next field. The date is returned every time, but the menu
+    /// only reports it once editing finishes after the day.
+    fn selected(&mut self) -> SelectedData<T> {
+        self.cursor.select(FIELD_COUNT);
+        SelectedData::Date {
+            id: self.id,
+            date: self.date,
+        }
+    }
+
+    fn display_string(&self) -> &str {
+        self.value_text.as_str()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::MenuTheme;
+    use crate::MenuStyleBuilder;
+    use embedded_graphics::mono_font::ascii::FONT_6X10;
+    use embedded_graphics::pixelcolor::BinaryColor;
+
+    fn date_item(date: Date) -> DateItem<'static, BinaryColor, u8> {
+        let menu_style = MenuStyleBuilder::new(&FONT_6X10, &FONT_6X10, MenuTheme::dark()).build();
+        DateItem::new("Date", 1, menu_style, date)
+    }
+
+    #[test]
+    fn leap_years() {
+        assert!(is_leap_year(2024));
+        assert!(is_leap_year(2000));
+        assert!(!is_leap_year(2023));
+        assert!(!is_leap_year(1900));
+        assert_eq!(days_in_month(2024, 2), 29);
+        assert_eq!(days_in_month(2100, 2), 28);
+        assert_eq!(days_in_month(2023, 4), 30);
+        assert_eq!(days_in_month(2023, 12), 31);
+    }
+
+    #[test]
+    fn only_real_dates_are_made() {
+        assert!(Date::new(2024, 2, 29).is_some());
+        assert!(Date::new(2023, 2, 29).is_none());
+        assert!(Date::new(2023, 13, 1).is_none());
+        assert!(Date::new(2023, 0, 1).is_none());
+        assert!(Date::new(2023, 1, 0).is_none());
+        assert!(Date::new(2023, 1, 32).is_none());
+    }
+
+    #[test]
+    fn day_follows_the_length_of_the_month() {
+        let mut item = date_item(Date::new(2023, 3, 31).unwrap());
+        item.selected();
+        item.edit(EditAction::Next);
+        item.edit(EditAction::Decrease);
+        assert_eq!(item.date(), Date::new(2023, 2, 28).unwrap());
+        assert_eq!(item.display_string(), "2023-02-28");
+
+        let mut item = date_item(Date::new(2024, 2, 29).unwrap());
+        item.selected();
+        item.edit(EditAction::Increase);
+        assert_eq!(item.date(), Date::new(2025, 2, 28).unwrap());
+    }
+
+    #[test]
+    fn day_wraps_within_the_month() {
+        let mut item = date_item(Date::new(2024, 2, 29).unwrap());
+        item.selected();
+        item.edit(EditAction::Next);
+        item.edit(EditAction::Next);
+        item.edit(EditAction::Increase);
+        assert_eq!(item.date(), Date::new(2024, 2, 1).unwrap());
+        item.edit(EditAction::Decrease);
+        assert_eq!(item.date(), Date::new(2024, 2, 29).unwrap());
+    }
+
+    #[test]
+    fn year_range_keeps_the_date_real() {
+        let mut item = date_item(Date::new(2024, 2, 29).unwrap());
+        item.set_year_range(2025, 2030);
+        assert_eq!(item.date(), Date::new(2025, 2, 28).unwrap());
+    }
+
+    #[test]
+    fn set_date_keeps_the_year_in_range() {
+        let mut item = date_item(Date::new(2024, 1, 1).unwrap());
+        item.set_date(Date::new(2104, 2, 29).unwrap());
+        assert_eq!(item.date(), Date::new(2099, 2, 28).unwrap());
+        assert_eq!(item.display_string(), "2099-02-28");
+    }
+
+    #[test]
+    fn five_digit_years_are_shown_whole() {
+        let mut item = date_item(Date::new(2024, 1, 1).unwrap());
+        item.set_year_range(10000, u16::MAX);
+        assert_eq!(item.display_string(), "10000-01-01");
+        item.set_date(Date::new(u16::MAX, 12, 31).unwrap());
+        assert_eq!(item.display_string(), "65535-12-31");
+    }
+}
@@ -0,0 +1,160 @@
+use crate::items::{text_width, EditAction};
+use crate::MenuStyle;
+use core::ops::Range;
+use embedded_graphics::draw_target::DrawTarget;
+use embedded_graphics::geometry::{Point, Size};
+use embedded_graphics::pixelcolor::PixelColor;
+use embedded_graphics::primitives::Rectangle;
+use embedded_graphics::text::{Baseline, Text};
+use embedded_graphics::Drawable;
+
+/// Field being edited in an item edited one field at a time, such as a time or an address.
+/// Select starts editing on the first field and steps to the next one, finishing after the last.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct FieldCursor {
+    field: Option<usize>,
+}
+
+impl FieldCursor {
+    pub const fn new() -> Self {
+        Self { field: None }
+    }
+
+    pub fn is_editing(&self) -> bool {
+        self.field.is_some()
+    }
+
+    pub fn field(&self) -> Option<usize> {
+        self.field
+    }
+
+    /// Start editing or step to the next of `field_count` fields, finishing after the last
+    pub fn select(&mut self, field_count: usize) {
+        self.field = match self.field {
+            None => Some(0),
+            Some(field) if field + 1 < field_count => Some(field + 1),
+            Some(_) => None,
+        };
+    }
+
+    /// Move between fields for [`EditAction::Next`] and [`EditAction::Previous`], staying on the
+    /// first and last field. Returns the field to change for the other actions.
+    pub fn apply(&mut self, action: EditAction, field_count: usize) -> Option<usize> {
+        let field = self.field?;
+        match action {
+            EditAction::Next => {
+                self.field = Some((field + 1).min(field_count.saturating_sub(1)));
+                None
+            }
+            EditAction::Previous => {
+                self.field = Some(field.saturating_sub(1));
+                None
+            }
+            EditAction::Increase | EditAction::Decrease => Some(field),
+        }
+    }
+}
+
+/// Step `value` by one within `min..=max`, wrapping round at either end like a wheel
+pub(crate) fn wheel_step(value: u32, min: u32, max: u32, action: EditAction) -> u32 {
+    match action {
+        EditAction::Increase if value >= max => min,
+        EditAction::Increase => value + 1,
+        EditAction::Decrease if value <= min => max,
+        EditAction::Decrease => value - 1,
+        EditAction::Next | EditAction::Previous => value,
+    }
+}
+
+/// Step the digit of `value` worth `place` in `base` by one, wrapping round within the digit
+/// and leaving the other digits alone
+pub(crate) fn digit_step(value: u32, place: u32, base: u32, action: EditAction) -> u32 {
+    let digit = value / place % base;
+    let stepped = wheel_step(digit, 0, base - 1, action);
+    value - digit * place + stepped * place
+}
+
+/// Draw the value text of a field editor right-aligned on the line starting at `top`, with the
+/// characters in `active_field` drawn inverted to show where the cursor is
+pub(crate) fn draw_fields<D, C>(
+    display: &mut D,
+    top: i32,
+    text: &str,
+    active_field: Option<Range<usize>>,
+    highlighted: bool,
+    menu_style: &MenuStyle<'_, C>,
+) -> Result<(), D::Error>
+where
+    D: DrawTarget<Color = C>,
+    C: PixelColor,
+{
+    let value_style = menu_style.content_value_style(highlighted);
+    let width = display.bounding_box().size.width as i32;
+    let text_left = width - text_width(&value_style, text) as i32;
+    Text::with_baseline(text, Point::new(text_left, top), value_style, Baseline::Top)
+        .draw(display)?;
+
+    let Some(active_field) = active_field.filter(|field| text.get(field.clone()).is_some()) else {
+        return Ok(());
+    };
+    let Some(value_color) = value_style.text_color else {
+        return Ok(());
+    };
+    let field_left = text_left + text_width(&value_style, &text[..active_field.start]) as i32;
+    let field_text = &text[active_field];
+    let field_area = Rectangle::new(
+        Point::new(field_left, top),
+        Size::new(
+            text_width(&value_style, field_text),
+            value_style.font.character_size.height,
+        ),
+    );
+    display.fill_solid(&field_area, value_color)?;
+    let mut field_style = value_style;
+    field_style.text_color = Some(menu_style.content_background_color(highlighted));
+    Text::with_baseline(
+        field_text,
+        Point::new(field_left, top),
+        field_style,
+        Baseline::Top,
+    )
+    .draw(display)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wheel_step_wraps_at_both_ends() {
+        assert_eq!(wheel_step(5, 1, 12, EditAction::Increase), 6);
+        assert_eq!(wheel_step(12, 1, 12, EditAction::Increase), 1);
+        assert_eq!(wheel_step(1, 1, 12, EditAction::Decrease), 12);
+        assert_eq!(wheel_step(5, 1, 12, EditAction::Next), 5);
+    }
+
+    #[test]
+    fn digit_step_leaves_other_digits_alone() {
+        assert_eq!(digit_step(0x1f3, 0x10, 16, EditAction::Increase), 0x103);
+        assert_eq!(digit_step(0x103, 0x10, 16, EditAction::Decrease), 0x1f3);
+        assert_eq!(digit_step(1234, 100, 10, EditAction::Increase), 1334);
+        assert_eq!(digit_step(1934, 100, 10, EditAction::Increase), 1034);
+        assert_eq!(digit_step(1034, 1, 10, EditAction::Decrease), 1033);
+    }
+
+    #[test]
+    fn cursor_steps_through_fields_and_finishes() {
+        let mut cursor = FieldCursor::new();
+        cursor.select(3);
+        assert_eq!(cursor.field(), Some(0));
+        assert_eq!(cursor.apply(EditAction::Previous, 3), None);
+        assert_eq!(cursor.field(), Some(0));
+        cursor.apply(EditAction::Next, 3);
+        cursor.apply(EditAction::Next, 3);
+        cursor.apply(EditAction::Next, 3);
+        assert_eq!(cursor.apply(EditAction::Increase, 3), Some(2));
+        cursor.select(3);
+        assert!(!cursor.is_editing());
+    }
+}
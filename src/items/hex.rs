@@ -0,0 +1,327 @@
+use crate::icon::label_position;
+use crate::items::field_editor::{digit_step, draw_fields, FieldCursor};
+use crate::items::properties::ItemProperties;
+use crate::items::{
+    text_width, DrawableHighlighted, EditAction, MenuItem, MenuItemData, SelectedData,
+};
+use crate::text_buffer::TextBuffer;
+use crate::MenuStyle;
+use core::fmt;
+use core::fmt::{Debug, Display, Formatter};
+use embedded_graphics::draw_target::DrawTarget;
+use embedded_graphics::geometry::Point;
+use embedded_graphics::pixelcolor::PixelColor;
+use embedded_graphics::primitives::Rectangle;
+use embedded_graphics::text::{Baseline, Text};
+use embedded_graphics::Drawable;
+use embedded_layout::View;
+
+/// Most digits a hex value can have, for a `u32`
+pub const MAX_HEX_DIGITS: usize = 8;
+
+/// Value text, "0x" and up to eight digits
+const VALUE_TEXT_LEN: usize = 2 + MAX_HEX_DIGITS;
+
+/// Editor for a fixed number of hex digits, such as a bus address or register value, shown as
+/// "0x00FF" and edited a digit at a time. Selecting the item starts editing the first digit; up
+/// and down change the digit, left and right move between digits, and selecting steps on to the
+/// next digit, finishing after the last. The value is kept at or below the maximum.
+#[derive(PartialEq, Clone, Copy)]
+pub struct HexItem<'a, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    label: &'static str,
+    position: Point,
+    pub(crate) properties: ItemProperties<'a, C>,
+    digits: usize,
+    value: u32,
+    max: u32,
+    value_text: TextBuffer<VALUE_TEXT_LEN>,
+    cursor: FieldCursor,
+    id: T,
+}
+
+impl<C, T> HexItem<'_, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    pub fn new<'a>(
+        label: &'static str,
+        id: T,
+        menu_style: MenuStyle<'a, C>,
+        digits: usize,
+        value: u32,
+    ) -> HexItem<'a, C, T> {
+        let mut item = HexItem {
+            label,
+            position: Point::zero(),
+            properties: ItemProperties::new(menu_style),
+            digits: digits.clamp(1, MAX_HEX_DIGITS),
+            value,
+            max: u32::MAX,
+            value_text: TextBuffer::new(),
+            cursor: FieldCursor::new(),
+            id,
+        };
+        item.set_value(value);
+        item
+    }
+
+    fn draw_item<D>(&self, display: &mut D, highlighted: bool) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let menu_style = self.properties.style();
+        let item_character_style = menu_style.content_text_style(highlighted);
+        let content_position = menu_style.content_position(self.position);
+        if let Some(icon) = &self.properties.icon() {
+            icon.draw_in_item(display, content_position, highlighted, &menu_style)?;
+        }
+
+        let label_position = label_position(&self.properties.icon(), content_position);
+        Text::with_baseline(
+            self.label,
+            label_position,
+            item_character_style,
+            Baseline::Top,
+        )
+        .draw(display)?;
+        self.properties
+            .draw_description(display, self.label, label_position, highlighted)?;
+
+        // The digits follow the "0x" prefix
+        let active_field = self.cursor.field().map(|field| field + 2..field + 3);
+        let value_top = match self.value_wraps(display.bounding_box().size().width) {
+            true => self.size().height as i32,
+            false => 0,
+        };
+        draw_fields(
+            display,
+            value_top,
+            self.display_string(),
+            active_field,
+            highlighted,
+            &menu_style,
+        )
+    }
+}
+
+impl<'a, C, T> HexItem<'a, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    /// Set the value, kept at or below the maximum
+    pub fn set_value(&mut self, value: u32) {
+        self.value = value.min(self.max());
+        self.update_value_text();
+    }
+
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+
+    /// Set the largest value that can be entered, which is also capped by the number of digits
+    pub fn set_max(&mut self, max: u32) {
+        self.max = max;
+        self.set_value(self.value);
+    }
+
+    /// The largest value that can be entered
+    pub fn max(&self) -> u32 {
+        let digits_max = match self.digits {
+            MAX_HEX_DIGITS => u32::MAX,
+            digits => (1 << (digits * 4)) - 1,
+        };
+        self.max.min(digits_max)
+    }
+
+    pub fn is_editing(&self) -> bool {
+        self.cursor.is_editing()
+    }
+
+    /// Change the digit under the cursor or move the cursor. Values are only reported once
+    /// editing finishes.
+    pub fn edit(&mut self, action: EditAction) -> Option<SelectedData<T>> {
+        if let Some(field) = self.cursor.apply(action, self.digits) {
+            let place = 1 << ((self.digits - 1 - field) * 4);
+            self.set_value(digit_step(self.value, place, 16, action));
+        }
+        None
+    }
+
+    fn update_value_text(&mut self) {
+        self.value_text = TextBuffer::from_fmt(format_args!("0x{:01$X}", self.value, self.digits));
+    }
+
+    /// Height of the item when drawn `width` wide, with a second line for the value if it doesn't
+    /// fit beside the label
+    pub fn height_in(&self, width: u32) -> u32 {
+        self.properties.height(self.label, self.value_wraps(width))
+    }
+
+    fn value_wraps(&self, width: u32) -> bool {
+        self.properties.value_wraps(
+            self.label,
+            self.position,
+            text_width(
+                &self.properties.style().value_character_style,
+                self.display_string(),
+            ),
+            width,
+        )
+    }
+}
+
+impl<C, T> MenuItem<T> for HexItem<'_, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    fn label(&self) -> &'static str {
+        self.label
+    }
+    fn id(&self) -> T {
+        self.id
+    }
+}
+
+impl<C: PixelColor, T> Debug for HexItem<'_, C, T>
+where
+    T: Clone + Copy + Sized,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "[\"{}\":Hex]", self.label)
+    }
+}
+
+impl<C: PixelColor, T> Display for HexItem<'_, C, T>
+where
+    T: Clone + Copy + Sized,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.label)
+    }
+}
+
+impl<C: PixelColor, T> View for HexItem<'_, C, T>
+where
+    T: Clone + Copy + Sized,
+{
+    fn translate_impl(&mut self, by: Point) {
+        self.position += by;
+    }
+
+    fn bounds(&self) -> Rectangle {
+        self.properties.bounds(self.label)
+    }
+}
+
+impl<C: PixelColor, T> Drawable for HexItem<'_, C, T>
+where
+    T: Clone + Copy + Sized,
+{
+    type Color = C;
+    type Output = ();
+
+    fn draw<D>(&self, display: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        self.draw_item(display, false)
+    }
+}
+
+impl<C: PixelColor, T> DrawableHighlighted for HexItem<'_, C, T>
+where
+    T: Clone + Copy + Sized,
+{
+    type Color = C;
+    type Output = ();
+
+    fn draw_highlighted<D>(&self, display: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        self.properties.style().draw_highlight(
+            display,
+            self.position,
+            self.height_in(display.bounding_box().size().width),
+        )?;
+        self.draw_item(display, true)
+    }
+}
+
+impl<C, T> MenuItemData<T> for HexItem<'_, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    /// Start editing or step to the next digit, reporting the value once the last is done
+    fn selected(&mut self) -> SelectedData<T> {
+        self.cursor.select(self.digits);
+        SelectedData::Hex {
+            id: self.id,
+            value: self.value,
+        }
+    }
+
+    fn display_string(&self) -> &str {
+        self.value_text.as_str()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::MenuTheme;
+    use crate::MenuStyleBuilder;
+    use embedded_graphics::mono_font::ascii::FONT_6X10;
+    use embedded_graphics::pixelcolor::BinaryColor;
+
+    fn hex_item(digits: usize, value: u32) -> HexItem<'static, BinaryColor, u8> {
+        let menu_style = MenuStyleBuilder::new(&FONT_6X10, &FONT_6X10, MenuTheme::dark()).build();
+        HexItem::new("Register", 1, menu_style, digits, value)
+    }
+
+    #[test]
+    fn nibbles_are_edited_one_at_a_time_and_wrap_around() {
+        let mut item = hex_item(4, 0x00ff);
+        assert_eq!(item.display_string(), "0x00FF");
+        item.selected();
+        item.edit(EditAction::Increase);
+        item.edit(EditAction::Next);
+        item.edit(EditAction::Decrease);
+        item.selected();
+        item.edit(EditAction::Increase);
+        assert_eq!(item.value(), 0x1f0f);
+        assert_eq!(item.display_string(), "0x1F0F");
+    }
+
+    #[test]
+    fn values_stay_within_the_digits_and_the_maximum() {
+        assert_eq!(hex_item(2, 0x1234).value(), 0xff);
+        assert_eq!(hex_item(0, 0xab).display_string(), "0xF");
+        assert_eq!(hex_item(MAX_HEX_DIGITS + 1, u32::MAX).value(), u32::MAX);
+
+        let mut item = hex_item(4, 0x0123);
+        item.set_max(0x0fff);
+        item.selected();
+        item.edit(EditAction::Increase);
+        assert_eq!(item.value(), 0x0fff);
+    }
+
+    #[test]
+    fn the_value_is_reported_once_the_last_digit_is_done() {
+        let mut item = hex_item(2, 0x10);
+        item.selected();
+        assert_eq!(item.edit(EditAction::Decrease), None);
+        item.selected();
+        assert!(item.is_editing());
+        assert_eq!(item.selected(), SelectedData::Hex { id: 1, value: 0x00 });
+        assert!(!item.is_editing());
+    }
+}
@@ -0,0 +1,337 @@
+use crate::icon::label_position;
+use crate::items::field_editor::{digit_step, draw_fields, FieldCursor};
+use crate::items::properties::ItemProperties;
+use crate::items::{
+    text_width, DrawableHighlighted, EditAction, MenuItem, MenuItemData, SelectedData,
+};
+use crate::text_buffer::TextBuffer;
+use crate::MenuStyle;
+use core::fmt;
+use core::fmt::{Debug, Display, Formatter};
+use core::net::Ipv4Addr;
+use embedded_graphics::draw_target::DrawTarget;
+use embedded_graphics::geometry::Point;
+use embedded_graphics::pixelcolor::PixelColor;
+use embedded_graphics::primitives::Rectangle;
+use embedded_graphics::text::{Baseline, Text};
+use embedded_graphics::Drawable;
+use embedded_layout::View;
+
+/// Three digits for each of the four octets
+const FIELD_COUNT: usize = 12;
+
+/// Value text, "255.255.255.255"
+const VALUE_TEXT_LEN: usize = 15;
+
+/// Editor for a dotted IPv4 address such as a device address or subnet mask, shown with every
+/// octet zero padded as "192.168.001.010" and edited a digit at a time. Selecting the item starts
+/// editing the first digit; up and down change the digit, left and right move between digits, and
+/// selecting steps on to the next digit, finishing after the last. An octet that would go past
+/// 255 is kept at 255.
+#[derive(PartialEq, Clone, Copy)]
+pub struct Ipv4Item<'a, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    label: &'static str,
+    position: Point,
+    pub(crate) properties: ItemProperties<'a, C>,
+    octets: [u8; 4],
+    value_text: TextBuffer<VALUE_TEXT_LEN>,
+    cursor: FieldCursor,
+    id: T,
+}
+
+impl<C, T> Ipv4Item<'_, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    pub fn new<'a>(
+        label: &'static str,
+        id: T,
+        menu_style: MenuStyle<'a, C>,
+        address: Ipv4Addr,
+    ) -> Ipv4Item<'a, C, T> {
+        let mut item = Ipv4Item {
+            label,
+            position: Point::zero(),
+            properties: ItemProperties::new(menu_style),
+            octets: address.octets(),
+            value_text: TextBuffer::new(),
+            cursor: FieldCursor::new(),
+            id,
+        };
+        item.update_value_text();
+        item
+    }
+
+    fn draw_item<D>(&self, display: &mut D, highlighted: bool) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let menu_style = self.properties.style();
+        let item_character_style = menu_style.content_text_style(highlighted);
+        let content_position = menu_style.content_position(self.position);
+        if let Some(icon) = &self.properties.icon() {
+            icon.draw_in_item(display, content_position, highlighted, &menu_style)?;
+        }
+
+        let label_position = label_position(&self.properties.icon(), content_position);
+        Text::with_baseline(
+            self.label,
+            label_position,
+            item_character_style,
+            Baseline::Top,
+        )
+        .draw(display)?;
+        self.properties
+            .draw_description(display, self.label, label_position, highlighted)?;
+
+        // Each octet is three digits followed by a dot
+        let active_field = self
+            .cursor
+            .field()
+            .map(|field| field / 3 * 4 + field % 3)
+            .map(|position| position..position + 1);
+        let value_top = match self.value_wraps(display.bounding_box().size().width) {
+            true => self.size().height as i32,
+            false => 0,
+        };
+        draw_fields(
+            display,
+            value_top,
+            self.display_string(),
+            active_field,
+            highlighted,
+            &menu_style,
+        )
+    }
+}
+
+impl<'a, C, T> Ipv4Item<'a, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    pub fn set_address(&mut self, address: Ipv4Addr) {
+        self.octets = address.octets();
+        self.update_value_text();
+    }
+
+    pub fn address(&self) -> Ipv4Addr {
+        Ipv4Addr::from(self.octets)
+    }
+
+    pub fn is_editing(&self) -> bool {
+        self.cursor.is_editing()
+    }
+
+    /// Change the digit under the cursor or move the cursor. Addresses are only reported once
+    /// editing finishes.
+    pub fn edit(&mut self, action: EditAction) -> Option<SelectedData<T>> {
+        if let Some(field) = self.cursor.apply(action, FIELD_COUNT) {
+            let octet = &mut self.octets[field / 3];
+            let place = 10u32.pow(2 - (field % 3) as u32);
+            *octet = digit_step(*octet as u32, place, 10, action).min(u8::MAX as u32) as u8;
+        }
+        self.update_value_text();
+        None
+    }
+
+    fn update_value_text(&mut self) {
+        let [a, b, c, d] = self.octets;
+        self.value_text = TextBuffer::from_fmt(format_args!("{:03}.{:03}.{:03}.{:03}", a, b, c, d));
+    }
+
+    /// Height of the item when drawn `width` wide, with a second line for the value if it doesn't
+    /// fit beside the label
+    pub fn height_in(&self, width: u32) -> u32 {
+        self.properties.height(self.label, self.value_wraps(width))
+    }
+
+    fn value_wraps(&self, width: u32) -> bool {
+        self.properties.value_wraps(
+            self.label,
+            self.position,
+            text_width(
+                &self.properties.style().value_character_style,
+                self.display_string(),
+            ),
+            width,
+        )
+    }
+}
+
+impl<C, T> MenuItem<T> for Ipv4Item<'_, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    fn label(&self) -> &'static str {
+        self.label
+    }
+    fn id(&self) -> T {
+        self.id
+    }
+}
+
+impl<C: PixelColor, T> Debug for Ipv4Item<'_, C, T>
+where
+    T: Clone + Copy + Sized,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "[\"{}\":Ipv4]", self.label)
+    }
+}
+
+impl<C: PixelColor, T> Display for Ipv4Item<'_, C, T>
+where
+    T: Clone + Copy + Sized,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.label)
+    }
+}
+
+impl<C: PixelColor, T> View for Ipv4Item<'_, C, T>
+where
+    T: Clone + Copy + Sized,
+{
+    fn translate_impl(&mut self, by: Point) {
+        self.position += by;
+    }
+
+    fn bounds(&self) -> Rectangle {
+        self.properties.bounds(self.label)
+    }
+}
+
+impl<C: PixelColor, T> Drawable for Ipv4Item<'_, C, T>
+where
+    T: Clone + Copy + Sized,
+{
+    type Color = C;
+    type Output = ();
+
+    fn draw<D>(&self, display: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        self.draw_item(display, false)
+    }
+}
+
+impl<C: PixelColor, T> DrawableHighlighted for Ipv4Item<'_, C, T>
+where
+    T: Clone + Copy + Sized,
+{
+    type Color = C;
+    type Output = ();
+
+    fn draw_highlighted<D>(&self, display: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        self.properties.style().draw_highlight(
+            display,
+            self.position,
+            self.height_in(display.bounding_box().size().width),
+        )?;
+        self.draw_item(display, true)
+    }
+}
+
+impl<C, T> MenuItemData<T> for Ipv4Item<'_, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    /// Start editing or step to the next digit, reporting the address once the last is done
+    fn selected(&mut self) -> SelectedData<T> {
+        self.cursor.select(FIELD_COUNT);
+        SelectedData::Ipv4 {
+            id: self.id,
+            address: self.address(),
+        }
+    }
+
+    fn display_string(&self) -> &str {
+        self.value_text.as_str()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::MenuTheme;
+    use crate::MenuStyleBuilder;
+    use embedded_graphics::mono_font::ascii::FONT_6X10;
+    use embedded_graphics::pixelcolor::BinaryColor;
+
+    fn ipv4_item(address: [u8; 4]) -> Ipv4Item<'static, BinaryColor, u8> {
+        let menu_style = MenuStyleBuilder::new(&FONT_6X10, &FONT_6X10, MenuTheme::dark()).build();
+        Ipv4Item::new("Address", 1, menu_style, Ipv4Addr::from(address))
+    }
+
+    #[test]
+    fn octets_are_edited_a_digit_at_a_time() {
+        let mut item = ipv4_item([192, 168, 1, 10]);
+        assert_eq!(item.display_string(), "192.168.001.010");
+        item.selected();
+        item.edit(EditAction::Decrease);
+        for _ in 0..8 {
+            item.edit(EditAction::Next);
+        }
+        item.edit(EditAction::Increase);
+        item.edit(EditAction::Previous);
+        item.edit(EditAction::Increase);
+        assert_eq!(item.address(), Ipv4Addr::new(92, 168, 12, 10));
+        assert_eq!(item.display_string(), "092.168.012.010");
+    }
+
+    #[test]
+    fn octets_stay_within_a_byte() {
+        let mut item = ipv4_item([192, 168, 1, 10]);
+        item.selected();
+        for _ in 0..3 {
+            item.edit(EditAction::Next);
+        }
+        // 268 is kept at 255
+        item.edit(EditAction::Increase);
+        assert_eq!(item.address(), Ipv4Addr::new(192, 255, 1, 10));
+    }
+
+    #[test]
+    fn digits_wrap_around() {
+        let mut item = ipv4_item([0, 0, 0, 9]);
+        item.selected();
+        for _ in 0..FIELD_COUNT {
+            item.edit(EditAction::Next);
+        }
+        item.edit(EditAction::Increase);
+        assert_eq!(item.address(), Ipv4Addr::new(0, 0, 0, 0));
+        item.edit(EditAction::Decrease);
+        assert_eq!(item.address(), Ipv4Addr::new(0, 0, 0, 9));
+    }
+
+    #[test]
+    fn the_address_is_reported_once_the_last_digit_is_done() {
+        let mut item = ipv4_item([10, 0, 0, 1]);
+        item.selected();
+        assert_eq!(item.edit(EditAction::Increase), None);
+        for _ in 1..FIELD_COUNT {
+            item.selected();
+        }
+        assert!(item.is_editing());
+        assert_eq!(
+            item.selected(),
+            SelectedData::Ipv4 {
+                id: 1,
+                address: Ipv4Addr::new(110, 0, 0, 1)
+            }
+        );
+        assert!(!item.is_editing());
+    }
+}
@@ -0,0 +1,329 @@
+use crate::icon::label_position;
+use crate::items::properties::ItemProperties;
+use crate::items::{
+    draw_description, draw_indicator, DrawableHighlighted, IndicatorDirection, MenuItem,
+    MenuItemData, SelectedData,
+};
+use crate::text_buffer::TextBuffer;
+use crate::MenuStyle;
+use core::fmt;
+use core::fmt::{Debug, Display, Formatter};
+use embedded_graphics::draw_target::{DrawTarget, DrawTargetExt};
+use embedded_graphics::geometry::Point;
+use embedded_graphics::pixelcolor::PixelColor;
+use embedded_graphics::primitives::Rectangle;
+use embedded_graphics::text::renderer::TextRenderer;
+use embedded_graphics::text::{Baseline, Text};
+use embedded_graphics::Drawable;
+use embedded_layout::View;
+
+/// Longest entry label drawn, in bytes. Longer labels are cut short.
+pub const MAX_ENTRY_LABEL_LEN: usize = 40;
+
+/// Entries shown by a [`ListItem`], read by index as they scroll into view. The list can be far
+/// longer than the menu could hold as items, such as log records or scanned devices. Sources
+/// that change while shown need interior mutability and a
+/// [`Menu::refresh_list`](crate::Menu::refresh_list) after each change.
+pub trait ListSource {
+    /// Number of entries in the list
+    fn len(&self) -> usize;
+
+    /// Write the label of the entry at `index` into `buf`
+    fn label(&self, index: usize, buf: &mut dyn fmt::Write) -> fmt::Result;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Sources are compared by identity, as two lists showing the same source are the same list
+impl PartialEq for dyn ListSource + '_ {
+    fn eq(&self, other: &Self) -> bool {
+        core::ptr::addr_eq(self, other)
+    }
+}
+
+/// Row opening a list of entries from a [`ListSource`]. Selecting the row shows the list in place
+/// of the menu, and selecting an entry reports its index with [`SelectedData::ListEntry`].
+#[derive(PartialEq, Clone, Copy)]
+pub struct ListItem<'a, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    label: &'static str,
+    position: Point,
+    pub(crate) properties: ItemProperties<'a, C>,
+    source: &'a dyn ListSource,
+    id: T,
+}
+
+impl<C, T> ListItem<'_, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    pub const fn new<'a>(
+        label: &'static str,
+        id: T,
+        menu_style: MenuStyle<'a, C>,
+        source: &'a dyn ListSource,
+    ) -> ListItem<'a, C, T> {
+        ListItem {
+            label,
+            position: Point::zero(),
+            properties: ItemProperties::new(menu_style),
+            source,
+            id,
+        }
+    }
+
+    fn draw_item<D>(&self, display: &mut D, highlighted: bool) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let menu_style = self.properties.style();
+        let item_character_style = menu_style.content_text_style(highlighted);
+        let content_position = menu_style.content_position(self.position);
+        if let Some(icon) = &self.properties.icon() {
+            icon.draw_in_item(display, content_position, highlighted, &menu_style)?;
+        }
+
+        let first_line_height = self.properties.first_line_bounds(self.label).size.height;
+        let label_area = draw_indicator(
+            display,
+            IndicatorDirection::Right,
+            first_line_height,
+            menu_style.content_indicator_color(highlighted),
+        )?;
+        let mut label_display = display.cropped(&label_area);
+
+        let label_position = label_position(&self.properties.icon(), content_position);
+        Text::with_baseline(
+            self.label,
+            label_position,
+            item_character_style,
+            Baseline::Top,
+        )
+        .draw(&mut label_display)?;
+        draw_description(
+            &mut label_display,
+            self.properties.description(),
+            label_position + Point::new(0, first_line_height as i32),
+            highlighted,
+            &menu_style,
+        )?;
+
+        Ok(())
+    }
+}
+
+impl<'a, C, T> ListItem<'a, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    pub fn source(&self) -> &'a dyn ListSource {
+        self.source
+    }
+
+    /// Height of every entry row while the list is shown
+    pub(crate) fn entry_height(&self) -> u32 {
+        let menu_style = self.properties.style();
+        menu_style
+            .item_character_style
+            .font
+            .character_size
+            .height
+            .max(menu_style.highlight_text_style.line_height())
+    }
+
+    /// Draw the row of the entry at `index` at the top of `display`
+    pub(crate) fn draw_entry<D>(
+        &self,
+        display: &mut D,
+        index: usize,
+        highlighted: bool,
+    ) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let menu_style = self.properties.style();
+        if highlighted {
+            menu_style.draw_highlight(display, Point::zero(), self.entry_height())?;
+        }
+        let mut label = TextBuffer::<MAX_ENTRY_LABEL_LEN>::new();
+        // A source that fails part way still shows what it wrote
+        let _ = self.source.label(index, &mut label);
+        Text::with_baseline(
+            label.as_str(),
+            menu_style.content_position(Point::zero()),
+            menu_style.content_text_style(highlighted),
+            Baseline::Top,
+        )
+        .draw(display)?;
+        Ok(())
+    }
+}
+
+impl<C, T> MenuItem<T> for ListItem<'_, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    fn label(&self) -> &'static str {
+        self.label
+    }
+
+    fn id(&self) -> T {
+        self.id
+    }
+}
+
+impl<C, T> MenuItemData<T> for ListItem<'_, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    /// Open the list, which the menu then shows like a submenu
+    fn selected(&mut self) -> SelectedData<T> {
+        SelectedData::Submenu { id: self.id }
+    }
+
+    fn display_string(&self) -> &str {
+        self.label()
+    }
+}
+
+impl<C, T> Debug for ListItem<'_, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "[\"{}\":List]", self.label)
+    }
+}
+
+impl<C, T> Display for ListItem<'_, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.label)
+    }
+}
+
+impl<C, T> View for ListItem<'_, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    fn translate_impl(&mut self, by: Point) {
+        self.position += by;
+    }
+
+    fn bounds(&self) -> Rectangle {
+        self.properties.bounds(self.label)
+    }
+}
+
+impl<C, T> Drawable for ListItem<'_, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    type Color = C;
+    type Output = ();
+
+    fn draw<D>(&self, display: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        self.draw_item(display, false)
+    }
+}
+
+impl<C: PixelColor, T> DrawableHighlighted for ListItem<'_, C, T>
+where
+    T: Clone + Copy + Sized,
+{
+    type Color = C;
+    type Output = ();
+
+    fn draw_highlighted<D>(&self, display: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        self.properties
+            .style()
+            .draw_highlight(display, self.position, self.size().height)?;
+        self.draw_item(display, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::MenuTheme;
+    use crate::{Menu, MenuStyleBuilder};
+    use embedded_graphics::mono_font::ascii::FONT_6X10;
+    use embedded_graphics::pixelcolor::BinaryColor;
+
+    const LIST_ID: u32 = 1;
+
+    /// `len` numbered entries
+    struct Numbers {
+        len: usize,
+    }
+
+    impl ListSource for Numbers {
+        fn len(&self) -> usize {
+            self.len
+        }
+
+        fn label(&self, index: usize, buf: &mut dyn fmt::Write) -> fmt::Result {
+            write!(buf, "Entry {index}")
+        }
+    }
+
+    fn menu_with_list(source: &Numbers) -> Menu<'_, BinaryColor, u32> {
+        let style = MenuStyleBuilder::new(&FONT_6X10, &FONT_6X10, MenuTheme::dark()).build();
+        let mut menu = Menu::new("Menu", 0, style);
+        menu.add_list("Numbers", LIST_ID, source);
+        menu
+    }
+
+    #[test]
+    fn selecting_an_entry_reports_its_index() {
+        let source = Numbers { len: 3 };
+        let mut menu = menu_with_list(&source);
+        assert_eq!(
+            menu.select_item(),
+            Some(SelectedData::Submenu { id: LIST_ID })
+        );
+        menu.navigate_down();
+        assert_eq!(
+            menu.select_item(),
+            Some(SelectedData::ListEntry {
+                id: LIST_ID,
+                index: 1
+            })
+        );
+    }
+
+    #[test]
+    fn an_empty_list_has_nothing_to_select() {
+        let source = Numbers { len: 0 };
+        let mut menu = menu_with_list(&source);
+        assert_eq!(
+            menu.select_item(),
+            Some(SelectedData::Submenu { id: LIST_ID })
+        );
+        menu.navigate_down();
+        assert_eq!(menu.select_item(), None);
+        menu.navigate_up();
+        assert_eq!(menu.select_item(), None);
+    }
+}
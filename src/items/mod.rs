@@ -1,35 +1,133 @@
+use crate::footer::SoftKeyHints;
+use crate::icon::MenuIcon;
+use crate::{MenuStyle, StyleOverride};
 use action::ActionItem;
 use back_item::BackItem;
-use checkbox::CheckboxItem;
+use checkbox::{CheckboxItem, CheckboxStyle};
 use core::fmt::{Display, Formatter};
-use embedded_graphics::draw_target::DrawTarget;
-use embedded_graphics::geometry::Point;
+use core::net::Ipv4Addr;
+use custom::{CustomItem, CustomMenuItem, NoCustomItems};
+use date::{Date, DateItem};
+use embedded_graphics::draw_target::{DrawTarget, DrawTargetExt};
+use embedded_graphics::geometry::{AnchorX, Point, Size};
+use embedded_graphics::mono_font::MonoTextStyle;
 use embedded_graphics::prelude::PixelColor;
-use embedded_graphics::primitives::Rectangle;
+use embedded_graphics::prelude::Primitive;
+use embedded_graphics::primitives::{PrimitiveStyle, Rectangle, Triangle};
+use embedded_graphics::text::renderer::TextRenderer;
+use embedded_graphics::text::{Baseline, Text};
 use embedded_graphics::Drawable;
 use embedded_layout::View;
 use exit_item::ExitItem;
+use hex::HexItem;
+use ipv4::Ipv4Item;
+use list::ListItem;
 use multi_option::MultiOptionItem;
+use pin::{PinCode, PinItem};
+use progress::ProgressItem;
+use properties::ItemProperties;
+use radio::RadioItem;
 use section::SectionItem;
+use slider::SliderItem;
 use submenu::SubmenuItem;
+use time::{Time, TimeItem};
 
 pub mod action;
 pub mod back_item;
 pub mod checkbox;
+pub mod custom;
+pub mod date;
 pub mod exit_item;
+mod field_editor;
+pub mod hex;
+pub mod ipv4;
+pub mod list;
 pub mod multi_option;
+pub mod pin;
+pub mod progress;
+mod properties;
+pub mod radio;
 pub mod section;
+pub mod slider;
 pub mod submenu;
+pub mod time;
 
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub enum SelectedData<T> {
-    Checkbox { id: T, state: bool },
-    Submenu { id: T },
-    Back { id: T },
-    MultiOption { id: T, option_id: usize },
-    Section { id: T },
-    Action { id: T },
-    Exit { id: T },
+    Checkbox {
+        id: T,
+        state: bool,
+    },
+    Submenu {
+        id: T,
+    },
+    /// An entry of a list shown from a [`ListSource`](list::ListSource)
+    ListEntry {
+        id: T,
+        index: usize,
+    },
+    /// A submenu was not opened as it is nested deeper than
+    /// [`MAX_MENU_DEPTH`](crate::MAX_MENU_DEPTH)
+    TooDeep {
+        id: T,
+    },
+    /// A locked submenu was not opened, after a wrong code or because it is locked out
+    AccessDenied {
+        id: T,
+        locked_out: bool,
+    },
+    Back {
+        id: T,
+    },
+    MultiOption {
+        id: T,
+        option_id: usize,
+    },
+    Slider {
+        id: T,
+        value: i32,
+    },
+    Progress {
+        id: T,
+    },
+    Time {
+        id: T,
+        time: Time,
+    },
+    Date {
+        id: T,
+        date: Date,
+    },
+    Ipv4 {
+        id: T,
+        address: Ipv4Addr,
+    },
+    Hex {
+        id: T,
+        value: u32,
+    },
+    Pin {
+        id: T,
+        code: PinCode,
+    },
+    Section {
+        id: T,
+    },
+    Action {
+        id: T,
+    },
+    Exit {
+        id: T,
+    },
+}
+
+/// Change made with the navigation keys to an item being edited
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum EditAction {
+    Increase,
+    Decrease,
+    Next,
+    Previous,
 }
 
 pub trait MenuItem<T>: View + Drawable + DrawableHighlighted + Display + MenuItemData<T> {
@@ -54,34 +152,409 @@ pub trait MenuItemData<T> {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub enum MenuItems<'a, C, T>
+pub enum MenuItems<'a, C, T, X = NoCustomItems<C>>
 where
     C: PixelColor,
     T: Clone + Copy + Sized,
 {
     Checkbox(CheckboxItem<'a, C, T>),
-    Submenu(SubmenuItem<'a, C, T>),
+    Submenu(SubmenuItem<'a, C, T, X>),
+    List(ListItem<'a, C, T>),
     Selector(MultiOptionItem<'a, C, T>),
+    Radio(RadioItem<'a, C, T>),
+    Slider(SliderItem<'a, C, T>),
+    Progress(ProgressItem<'a, C, T>),
+    Time(TimeItem<'a, C, T>),
+    Date(DateItem<'a, C, T>),
+    Ipv4(Ipv4Item<'a, C, T>),
+    Hex(HexItem<'a, C, T>),
+    Pin(PinItem<'a, C, T>),
     Section(SectionItem<'a, C, T>),
     Back(BackItem<'a, C, T>),
     Action(ActionItem<'a, C, T>),
     Exit(ExitItem<'a, C, T>),
+    Custom(CustomItem<'a, C, X>),
 }
 
-impl<C, T> View for MenuItems<'_, C, T>
+impl<'a, C, T, X> MenuItems<'a, C, T, X>
 where
     C: PixelColor,
     T: Copy + Clone + Sized,
+    X: CustomMenuItem<'a, C, T>,
+{
+    /// Icon and other properties every kind of item shares
+    pub(crate) fn properties(&self) -> &ItemProperties<'a, C> {
+        match self {
+            MenuItems::Checkbox(item) => &item.properties,
+            MenuItems::Submenu(item) => &item.properties,
+            MenuItems::List(item) => &item.properties,
+            MenuItems::Selector(item) => &item.properties,
+            MenuItems::Radio(item) => &item.properties,
+            MenuItems::Slider(item) => &item.properties,
+            MenuItems::Time(item) => &item.properties,
+            MenuItems::Date(item) => &item.properties,
+            MenuItems::Ipv4(item) => &item.properties,
+            MenuItems::Hex(item) => &item.properties,
+            MenuItems::Pin(item) => &item.properties,
+            MenuItems::Progress(item) => &item.properties,
+            MenuItems::Section(item) => &item.properties,
+            MenuItems::Back(item) => &item.properties,
+            MenuItems::Action(item) => &item.properties,
+            MenuItems::Exit(item) => &item.properties,
+            MenuItems::Custom(item) => &item.properties,
+        }
+    }
+
+    pub(crate) fn properties_mut(&mut self) -> &mut ItemProperties<'a, C> {
+        match self {
+            MenuItems::Checkbox(item) => &mut item.properties,
+            MenuItems::Submenu(item) => &mut item.properties,
+            MenuItems::List(item) => &mut item.properties,
+            MenuItems::Selector(item) => &mut item.properties,
+            MenuItems::Radio(item) => &mut item.properties,
+            MenuItems::Slider(item) => &mut item.properties,
+            MenuItems::Time(item) => &mut item.properties,
+            MenuItems::Date(item) => &mut item.properties,
+            MenuItems::Ipv4(item) => &mut item.properties,
+            MenuItems::Hex(item) => &mut item.properties,
+            MenuItems::Pin(item) => &mut item.properties,
+            MenuItems::Progress(item) => &mut item.properties,
+            MenuItems::Section(item) => &mut item.properties,
+            MenuItems::Back(item) => &mut item.properties,
+            MenuItems::Action(item) => &mut item.properties,
+            MenuItems::Exit(item) => &mut item.properties,
+            MenuItems::Custom(item) => &mut item.properties,
+        }
+    }
+
+    pub fn set_icon(&mut self, icon: Option<MenuIcon<'a, C>>) {
+        match self {
+            // Custom items draw themselves, icon included if they have one
+            MenuItems::Custom(_) => {}
+            _ => self.properties_mut().set_icon(icon),
+        }
+    }
+
+    pub fn icon(&self) -> Option<MenuIcon<'a, C>> {
+        self.properties().icon()
+    }
+
+    pub fn set_menu_style(&mut self, menu_style: MenuStyle<'a, C>) {
+        match self {
+            MenuItems::Custom(item) => item.set_menu_style(menu_style),
+            _ => self.properties_mut().set_menu_style(menu_style),
+        }
+    }
+
+    pub fn set_style_override(&mut self, style_override: Option<StyleOverride<'a, C>>) {
+        match self {
+            MenuItems::Custom(item) => item.set_style_override(style_override),
+            _ => self.properties_mut().set_style_override(style_override),
+        }
+    }
+
+    pub fn style_override(&self) -> Option<StyleOverride<'a, C>> {
+        self.properties().style_override()
+    }
+
+    pub fn set_soft_keys(&mut self, soft_keys: Option<SoftKeyHints>) {
+        self.properties_mut().set_soft_keys(soft_keys);
+    }
+
+    pub fn soft_keys(&self) -> Option<SoftKeyHints> {
+        self.properties().soft_keys()
+    }
+
+    pub fn set_description(&mut self, description: Option<&'static str>) {
+        match self {
+            // Section headings have no description, custom items draw their own
+            MenuItems::Section(_) | MenuItems::Custom(_) => {}
+            _ => self.properties_mut().set_description(description),
+        }
+    }
+
+    pub fn description(&self) -> Option<&'static str> {
+        self.properties().description()
+    }
+
+    pub fn set_help_text(&mut self, help_text: Option<&'static str>) {
+        match self {
+            // Section headings and progress items can't be highlighted so their help would never
+            // be shown
+            MenuItems::Section(_) | MenuItems::Progress(_) => {}
+            _ => self.properties_mut().set_help_text(help_text),
+        }
+    }
+
+    pub fn help_text(&self) -> Option<&'static str> {
+        self.properties().help_text()
+    }
+
+    /// Change how a checkbox item draws its state. Other items are unaffected.
+    pub fn set_checkbox_style(&mut self, checkbox_style: Option<CheckboxStyle>) {
+        if let MenuItems::Checkbox(item) = self {
+            item.set_checkbox_style(checkbox_style);
+        }
+    }
+
+    /// Footer hints for this item: its own if set, otherwise ones describing what selecting this
+    /// kind of item does
+    pub fn soft_key_hints(&self) -> SoftKeyHints {
+        if let Some(soft_keys) = self.soft_keys() {
+            return soft_keys;
+        }
+        let select_hint = match self {
+            MenuItems::Checkbox(_) => "Toggle",
+            MenuItems::Submenu(item) if item.is_editing() => {
+                return SoftKeyHints::new("", "Next", "")
+            }
+            MenuItems::Submenu(_) | MenuItems::List(_) => "Open",
+            MenuItems::Selector(_) => "Change",
+            MenuItems::Radio(_) => "Choose",
+            MenuItems::Slider(item) if item.is_editing() => {
+                return SoftKeyHints::new("-", "Done", "+")
+            }
+            MenuItems::Slider(_) => "Edit",
+            MenuItems::Progress(_) => "",
+            MenuItems::Time(_)
+            | MenuItems::Date(_)
+            | MenuItems::Ipv4(_)
+            | MenuItems::Hex(_)
+            | MenuItems::Pin(_)
+                if self.is_editing() =>
+            {
+                return SoftKeyHints::new("", "Next", "");
+            }
+            MenuItems::Time(_) | MenuItems::Date(_) | MenuItems::Ipv4(_) | MenuItems::Hex(_) => {
+                "Edit"
+            }
+            MenuItems::Pin(_) => "Enter",
+            MenuItems::Section(_) => "",
+            MenuItems::Back(_) => "Back",
+            MenuItems::Action(_) => "Select",
+            MenuItems::Exit(_) => "Exit",
+            MenuItems::Custom(item) => return item.item().soft_key_hints(),
+        };
+        SoftKeyHints::new("Back", select_hint, "")
+    }
+
+    /// Whether the item can be highlighted. Navigation passes over section headings, progress
+    /// bars and custom items that ask to be.
+    pub fn is_highlightable(&self) -> bool {
+        match self {
+            MenuItems::Section(_) | MenuItems::Progress(_) => false,
+            MenuItems::Custom(item) => item.item().is_highlightable(),
+            _ => true,
+        }
+    }
+
+    /// Whether the item is being edited, with navigation changing its value rather than moving
+    /// the highlight
+    pub fn is_editing(&self) -> bool {
+        match self {
+            MenuItems::Submenu(item) => item.is_editing(),
+            MenuItems::Slider(item) => item.is_editing(),
+            MenuItems::Time(item) => item.is_editing(),
+            MenuItems::Date(item) => item.is_editing(),
+            MenuItems::Ipv4(item) => item.is_editing(),
+            MenuItems::Hex(item) => item.is_editing(),
+            MenuItems::Pin(item) => item.is_editing(),
+            MenuItems::Custom(item) => item.item().is_editing(),
+            _ => false,
+        }
+    }
+
+    /// Apply a navigation key to the item being edited, returning a change to report straight
+    /// away if there is one
+    pub fn edit(&mut self, action: EditAction) -> Option<SelectedData<T>> {
+        match self {
+            MenuItems::Submenu(item) => item.edit(action),
+            MenuItems::Slider(item) => item.edit(action),
+            MenuItems::Time(item) => item.edit(action),
+            MenuItems::Date(item) => item.edit(action),
+            MenuItems::Ipv4(item) => item.edit(action),
+            MenuItems::Hex(item) => item.edit(action),
+            MenuItems::Pin(item) => item.edit(action),
+            MenuItems::Custom(item) => item.edit(action),
+            _ => None,
+        }
+    }
+
+    /// Height of the item when drawn `width` wide, which is more than its bounds for items that
+    /// move their value to a second line when it doesn't fit beside the label
+    pub fn height_in(&self, width: u32) -> u32 {
+        match self {
+            MenuItems::Checkbox(item) => item.height_in(width),
+            MenuItems::Selector(item) => item.height_in(width),
+            MenuItems::Slider(item) => item.height_in(width),
+            MenuItems::Progress(item) => item.height_in(width),
+            MenuItems::Time(item) => item.height_in(width),
+            MenuItems::Date(item) => item.height_in(width),
+            MenuItems::Ipv4(item) => item.height_in(width),
+            MenuItems::Hex(item) => item.height_in(width),
+            MenuItems::Pin(item) => item.height_in(width),
+            MenuItems::Radio(item) => item.height_in(width),
+            _ => self.size().height,
+        }
+    }
+}
+
+/// Value fonts shorter than this draw item state as text rather than shapes, which wouldn't be
+/// legible at that size
+pub(crate) const MIN_DRAWN_FONT_HEIGHT: u32 = 8;
+
+/// Area of the bar drawn by sliders and progress items in an item `width` wide, taking the right
+/// two fifths of the first line
+pub(crate) fn bar_area<C: PixelColor>(value_style: &MonoTextStyle<'_, C>, width: u32) -> Rectangle {
+    let bar_width = width * 2 / 5;
+    Rectangle::new(
+        Point::new((width - bar_width) as i32, 1),
+        Size::new(
+            bar_width,
+            value_style.font.character_size.height.saturating_sub(2),
+        ),
+    )
+}
+
+/// Draw the outline of a bar with the part from `start` to `end` out of `total` filled in
+pub(crate) fn draw_bar<D, C>(
+    display: &mut D,
+    bar_area: Rectangle,
+    start: i64,
+    end: i64,
+    total: i64,
+    color: C,
+) -> Result<(), D::Error>
+where
+    D: DrawTarget<Color = C>,
+    C: PixelColor,
+{
+    bar_area
+        .into_styled(PrimitiveStyle::with_stroke(color, 1))
+        .draw(display)?;
+    let fill_area = bar_area.offset(-2);
+    let fill_width = fill_area.size.width as i64;
+    let (fill_start, fill_end) = match total {
+        0 => (0, fill_width),
+        _ => (start * fill_width / total, end * fill_width / total),
+    };
+    let fill_start = fill_start.clamp(0, fill_width);
+    let fill_end = fill_end.clamp(fill_start, fill_width);
+    Rectangle::new(
+        fill_area.top_left + Point::new(fill_start as i32, 0),
+        Size::new((fill_end - fill_start) as u32, fill_area.size.height),
+    )
+    .into_styled(PrimitiveStyle::with_fill(color))
+    .draw(display)
+}
+
+/// Width of `text` drawn with `text_style`
+pub(crate) fn text_width<C: PixelColor>(text_style: &MonoTextStyle<'_, C>, text: &str) -> u32 {
+    text_style
+        .measure_string(text, Point::zero(), Baseline::Top)
+        .bounding_box
+        .size
+        .width
+}
+
+/// Draw an item description, if it has one, with its top left at `position`
+pub(crate) fn draw_description<D, C>(
+    display: &mut D,
+    description: Option<&str>,
+    position: Point,
+    highlighted: bool,
+    menu_style: &MenuStyle<'_, C>,
+) -> Result<(), D::Error>
+where
+    D: DrawTarget<Color = C>,
+    C: PixelColor,
+{
+    if let Some(description) = description {
+        Text::with_baseline(
+            description,
+            position,
+            menu_style.content_description_style(highlighted),
+            Baseline::Top,
+        )
+        .draw(display)?;
+    }
+    Ok(())
+}
+
+/// Which way the triangle at the right end of a row points
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) enum IndicatorDirection {
+    /// Into a submenu, list or action
+    Right,
+    /// Out of the menu
+    Left,
+}
+
+/// Draw the triangle at the right end of an item's first line, `first_line_height` tall,
+/// returning the rest of the row for the label
+pub(crate) fn draw_indicator<D, C>(
+    display: &mut D,
+    direction: IndicatorDirection,
+    first_line_height: u32,
+    color: C,
+) -> Result<Rectangle, D::Error>
+where
+    D: DrawTarget<Color = C>,
+    C: PixelColor,
+{
+    let vertical_pad = 2u32;
+    let right_pad = 2u32;
+    let indicator_size = Size::new(first_line_height / 2, first_line_height);
+    let display_area = display.bounding_box();
+    let indicator_area = display_area.resized_width(indicator_size.width, AnchorX::Right);
+
+    let tip_x = (indicator_size.width - right_pad) as i32;
+    let (base_x, tip_x) = match direction {
+        IndicatorDirection::Right => (0, tip_x),
+        IndicatorDirection::Left => (tip_x, 0),
+    };
+    Triangle::new(
+        Point::new(base_x, vertical_pad as i32),
+        Point::new(base_x, (indicator_size.height - vertical_pad) as i32),
+        Point::new(
+            tip_x,
+            (((indicator_size.height - vertical_pad * 2) / 2) + vertical_pad) as i32,
+        ),
+    )
+    .into_styled(PrimitiveStyle::with_fill(color))
+    .draw(&mut display.cropped(&indicator_area))?;
+
+    Ok(display_area.resized_width(
+        display_area.size.width - indicator_size.width,
+        AnchorX::Left,
+    ))
+}
+
+impl<'a, C, T, X> View for MenuItems<'a, C, T, X>
+where
+    C: PixelColor,
+    T: Copy + Clone + Sized,
+    X: CustomMenuItem<'a, C, T>,
 {
     fn translate_impl(&mut self, by: Point) {
         match self {
             MenuItems::Checkbox(item) => item.translate_impl(by),
             MenuItems::Submenu(item) => item.translate_impl(by),
+            MenuItems::List(item) => item.translate_impl(by),
             MenuItems::Selector(item) => item.translate_impl(by),
+            MenuItems::Radio(item) => item.translate_impl(by),
+            MenuItems::Slider(item) => item.translate_impl(by),
+            MenuItems::Time(item) => item.translate_impl(by),
+            MenuItems::Date(item) => item.translate_impl(by),
+            MenuItems::Ipv4(item) => item.translate_impl(by),
+            MenuItems::Hex(item) => item.translate_impl(by),
+            MenuItems::Pin(item) => item.translate_impl(by),
+            MenuItems::Progress(item) => item.translate_impl(by),
             MenuItems::Section(item) => item.translate_impl(by),
             MenuItems::Back(item) => item.translate_impl(by),
             MenuItems::Action(item) => item.translate_impl(by),
             MenuItems::Exit(item) => item.translate_impl(by),
+            MenuItems::Custom(item) => item.translate_impl(by),
         }
     }
 
@@ -89,47 +562,79 @@ where
         match self {
             MenuItems::Checkbox(item) => item.bounds(),
             MenuItems::Submenu(item) => item.bounds(),
+            MenuItems::List(item) => item.bounds(),
             MenuItems::Selector(item) => item.bounds(),
+            MenuItems::Radio(item) => item.bounds(),
+            MenuItems::Slider(item) => item.bounds(),
+            MenuItems::Time(item) => item.bounds(),
+            MenuItems::Date(item) => item.bounds(),
+            MenuItems::Ipv4(item) => item.bounds(),
+            MenuItems::Hex(item) => item.bounds(),
+            MenuItems::Pin(item) => item.bounds(),
+            MenuItems::Progress(item) => item.bounds(),
             MenuItems::Section(item) => item.bounds(),
             MenuItems::Back(item) => item.bounds(),
             MenuItems::Action(item) => item.bounds(),
             MenuItems::Exit(item) => item.bounds(),
+            MenuItems::Custom(item) => item.bounds(),
         }
     }
 }
 
-impl<C, T> Display for MenuItems<'_, C, T>
+impl<'a, C, T, X> Display for MenuItems<'a, C, T, X>
 where
     C: PixelColor,
     T: Copy + Clone + Sized,
+    X: CustomMenuItem<'a, C, T>,
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         match self {
             MenuItems::Checkbox(item) => Display::fmt(&item, f),
             MenuItems::Submenu(item) => Display::fmt(&item, f),
+            MenuItems::List(item) => Display::fmt(&item, f),
             MenuItems::Selector(item) => Display::fmt(&item, f),
+            MenuItems::Radio(item) => Display::fmt(&item, f),
+            MenuItems::Slider(item) => Display::fmt(&item, f),
+            MenuItems::Time(item) => Display::fmt(&item, f),
+            MenuItems::Date(item) => Display::fmt(&item, f),
+            MenuItems::Ipv4(item) => Display::fmt(&item, f),
+            MenuItems::Hex(item) => Display::fmt(&item, f),
+            MenuItems::Pin(item) => Display::fmt(&item, f),
+            MenuItems::Progress(item) => Display::fmt(&item, f),
             MenuItems::Section(item) => Display::fmt(&item, f),
             MenuItems::Back(item) => Display::fmt(&item, f),
             MenuItems::Action(item) => Display::fmt(&item, f),
             MenuItems::Exit(item) => Display::fmt(&item, f),
+            MenuItems::Custom(item) => Display::fmt(&item, f),
         }
     }
 }
 
-impl<C, T> MenuItemData<T> for MenuItems<'_, C, T>
+impl<'a, C, T, X> MenuItemData<T> for MenuItems<'a, C, T, X>
 where
     C: PixelColor,
     T: Copy + Clone + Sized,
+    X: CustomMenuItem<'a, C, T>,
 {
     fn selected(&mut self) -> SelectedData<T> {
         match self {
             MenuItems::Checkbox(item) => item.selected(),
             MenuItems::Submenu(item) => item.selected(),
+            MenuItems::List(item) => item.selected(),
             MenuItems::Selector(item) => item.selected(),
+            MenuItems::Radio(item) => item.selected(),
+            MenuItems::Slider(item) => item.selected(),
+            MenuItems::Time(item) => item.selected(),
+            MenuItems::Date(item) => item.selected(),
+            MenuItems::Ipv4(item) => item.selected(),
+            MenuItems::Hex(item) => item.selected(),
+            MenuItems::Pin(item) => item.selected(),
+            MenuItems::Progress(item) => item.selected(),
             MenuItems::Section(item) => item.selected(),
             MenuItems::Back(item) => item.selected(),
             MenuItems::Action(item) => item.selected(),
             MenuItems::Exit(item) => item.selected(),
+            MenuItems::Custom(item) => item.selected(),
         }
     }
 
@@ -137,29 +642,50 @@ where
         match self {
             MenuItems::Checkbox(item) => item.display_string(),
             MenuItems::Submenu(item) => item.display_string(),
+            MenuItems::List(item) => item.display_string(),
             MenuItems::Selector(item) => item.display_string(),
+            MenuItems::Radio(item) => item.display_string(),
+            MenuItems::Slider(item) => item.display_string(),
+            MenuItems::Time(item) => item.display_string(),
+            MenuItems::Date(item) => item.display_string(),
+            MenuItems::Ipv4(item) => item.display_string(),
+            MenuItems::Hex(item) => item.display_string(),
+            MenuItems::Pin(item) => item.display_string(),
+            MenuItems::Progress(item) => item.display_string(),
             MenuItems::Section(item) => item.display_string(),
             MenuItems::Back(item) => item.display_string(),
             MenuItems::Action(item) => item.display_string(),
             MenuItems::Exit(item) => item.display_string(),
+            MenuItems::Custom(item) => item.display_string(),
         }
     }
 }
 
-impl<C, T> MenuItem<T> for MenuItems<'_, C, T>
+impl<'a, C, T, X> MenuItem<T> for MenuItems<'a, C, T, X>
 where
     C: PixelColor,
     T: Copy + Clone + Sized,
+    X: CustomMenuItem<'a, C, T>,
 {
     fn label(&self) -> &'static str {
         match self {
             MenuItems::Checkbox(item) => item.label(),
             MenuItems::Submenu(item) => item.label(),
+            MenuItems::List(item) => item.label(),
             MenuItems::Selector(item) => item.label(),
+            MenuItems::Radio(item) => item.label(),
+            MenuItems::Slider(item) => item.label(),
+            MenuItems::Time(item) => item.label(),
+            MenuItems::Date(item) => item.label(),
+            MenuItems::Ipv4(item) => item.label(),
+            MenuItems::Hex(item) => item.label(),
+            MenuItems::Pin(item) => item.label(),
+            MenuItems::Progress(item) => item.label(),
             MenuItems::Section(item) => item.label(),
             MenuItems::Back(item) => item.label(),
             MenuItems::Action(item) => item.label(),
             MenuItems::Exit(item) => item.label(),
+            MenuItems::Custom(item) => item.label(),
         }
     }
 
@@ -167,19 +693,30 @@ where
         match self {
             MenuItems::Checkbox(item) => item.id(),
             MenuItems::Submenu(item) => item.id(),
+            MenuItems::List(item) => item.id(),
             MenuItems::Selector(item) => item.id(),
+            MenuItems::Radio(item) => item.id(),
+            MenuItems::Slider(item) => item.id(),
+            MenuItems::Time(item) => item.id(),
+            MenuItems::Date(item) => item.id(),
+            MenuItems::Ipv4(item) => item.id(),
+            MenuItems::Hex(item) => item.id(),
+            MenuItems::Pin(item) => item.id(),
+            MenuItems::Progress(item) => item.id(),
             MenuItems::Section(item) => item.id(),
             MenuItems::Back(item) => item.id(),
             MenuItems::Action(item) => item.id(),
             MenuItems::Exit(item) => item.id(),
+            MenuItems::Custom(item) => item.id(),
         }
     }
 }
 
-impl<C, T> Drawable for MenuItems<'_, C, T>
+impl<'a, C, T, X> Drawable for MenuItems<'a, C, T, X>
 where
     C: PixelColor,
     T: Copy + Clone + Sized,
+    X: CustomMenuItem<'a, C, T>,
 {
     type Color = C;
     type Output = ();
@@ -191,18 +728,29 @@ where
         match self {
             MenuItems::Checkbox(item) => item.draw(display),
             MenuItems::Submenu(item) => item.draw(display),
+            MenuItems::List(item) => item.draw(display),
             MenuItems::Selector(item) => item.draw(display),
+            MenuItems::Radio(item) => item.draw(display),
+            MenuItems::Slider(item) => item.draw(display),
+            MenuItems::Time(item) => item.draw(display),
+            MenuItems::Date(item) => item.draw(display),
+            MenuItems::Ipv4(item) => item.draw(display),
+            MenuItems::Hex(item) => item.draw(display),
+            MenuItems::Pin(item) => item.draw(display),
+            MenuItems::Progress(item) => item.draw(display),
             MenuItems::Section(item) => item.draw(display),
             MenuItems::Back(item) => item.draw(display),
             MenuItems::Action(item) => item.draw(display),
             MenuItems::Exit(item) => item.draw(display),
+            MenuItems::Custom(item) => item.draw(display),
         }
     }
 }
 
-impl<C: PixelColor, T> DrawableHighlighted for MenuItems<'_, C, T>
+impl<'a, C: PixelColor, T, X> DrawableHighlighted for MenuItems<'a, C, T, X>
 where
     T: Copy + Clone + Sized,
+    X: CustomMenuItem<'a, C, T>,
 {
     type Color = C;
     type Output = ();
@@ -214,11 +762,98 @@ where
         match self {
             MenuItems::Checkbox(item) => item.draw_highlighted(display),
             MenuItems::Submenu(item) => item.draw_highlighted(display),
+            MenuItems::List(item) => item.draw_highlighted(display),
             MenuItems::Selector(item) => item.draw_highlighted(display),
+            MenuItems::Radio(item) => item.draw_highlighted(display),
+            MenuItems::Slider(item) => item.draw_highlighted(display),
+            MenuItems::Time(item) => item.draw_highlighted(display),
+            MenuItems::Date(item) => item.draw_highlighted(display),
+            MenuItems::Ipv4(item) => item.draw_highlighted(display),
+            MenuItems::Hex(item) => item.draw_highlighted(display),
+            MenuItems::Pin(item) => item.draw_highlighted(display),
+            MenuItems::Progress(item) => item.draw_highlighted(display),
             MenuItems::Section(item) => item.draw_highlighted(display),
             MenuItems::Back(item) => item.draw_highlighted(display),
             MenuItems::Action(item) => item.draw_highlighted(display),
             MenuItems::Exit(item) => item.draw_highlighted(display),
+            MenuItems::Custom(item) => item.draw_highlighted(display),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::MenuTheme;
+    use crate::MenuStyleBuilder;
+    use crate::Progress;
+    use embedded_graphics::mono_font::ascii::FONT_6X10;
+    use embedded_graphics::pixelcolor::BinaryColor;
+
+    fn style() -> MenuStyle<'static, BinaryColor> {
+        MenuStyleBuilder::new(&FONT_6X10, &FONT_6X10, MenuTheme::dark()).build()
+    }
+
+    /// Whether the value of `item` goes on a second line when drawn `width` wide
+    fn wraps_at(item: MenuItems<'_, BinaryColor, u32>, width: u32) -> bool {
+        item.height_in(width) > item.size().height
+    }
+
+    #[test]
+    fn values_wrap_only_when_the_label_leaves_no_room() {
+        let items = [
+            MenuItems::Slider(SliderItem::new("Brightness", 1, style(), 0, 100, 50)),
+            MenuItems::Progress(ProgressItem::new(
+                "Download",
+                1,
+                style(),
+                Progress::Percent(40),
+            )),
+            MenuItems::Time(TimeItem::new(
+                "Alarm",
+                1,
+                style(),
+                Time::new(7, 30, 0).unwrap(),
+            )),
+            MenuItems::Date(DateItem::new(
+                "Due",
+                1,
+                style(),
+                Date::new(2024, 2, 29).unwrap(),
+            )),
+            MenuItems::Ipv4(Ipv4Item::new(
+                "Host",
+                1,
+                style(),
+                Ipv4Addr::new(192, 168, 1, 10),
+            )),
+            MenuItems::Hex(HexItem::new("Register", 1, style(), 4, 0xbeef)),
+            MenuItems::Pin(PinItem::new("Code", 1, style(), 8)),
+            MenuItems::Radio(RadioItem::new(1, style(), &["Very high"], 0, true)),
+        ];
+        for item in items {
+            assert!(!wraps_at(item, 256), "{:?}", item);
+            assert!(wraps_at(item, 64), "{:?}", item);
+        }
+    }
+
+    #[test]
+    fn properties_are_shared_by_every_kind_of_item() {
+        let mut action: MenuItems<'_, BinaryColor, i32> =
+            MenuItems::Action(ActionItem::new("Action", 1, style()));
+        action.set_description(Some("What it does"));
+        action.set_help_text(Some("More about it"));
+        action.set_soft_keys(Some(SoftKeyHints::new("", "Go", "")));
+        assert_eq!(action.description(), Some("What it does"));
+        assert_eq!(action.help_text(), Some("More about it"));
+        assert_eq!(action.soft_key_hints(), SoftKeyHints::new("", "Go", ""));
+
+        // Sections can't be highlighted, so they take neither
+        let mut section: MenuItems<'_, BinaryColor, i32> =
+            MenuItems::Section(SectionItem::new("Section", 2, style()));
+        section.set_description(Some("What it does"));
+        section.set_help_text(Some("More about it"));
+        assert_eq!(section.description(), None);
+        assert_eq!(section.help_text(), None);
+    }
+}
@@ -1,4 +1,6 @@
-use crate::items::{DrawableHighlighted, MenuItem, MenuItemData, SelectedData};
+use crate::icon::label_position;
+use crate::items::properties::ItemProperties;
+use crate::items::{text_width, DrawableHighlighted, MenuItem, MenuItemData, SelectedData};
 use crate::MenuStyle;
 use core::cmp::min;
 use core::fmt;
@@ -6,9 +8,7 @@ use core::fmt::{Debug, Display, Formatter};
 use embedded_graphics::draw_target::DrawTarget;
 use embedded_graphics::geometry::Point;
 use embedded_graphics::pixelcolor::PixelColor;
-use embedded_graphics::prelude::{Primitive, Size};
-use embedded_graphics::primitives::{PrimitiveStyleBuilder, Rectangle};
-use embedded_graphics::text::renderer::TextRenderer;
+use embedded_graphics::primitives::Rectangle;
 use embedded_graphics::text::{Alignment, Baseline, Text, TextStyleBuilder};
 use embedded_graphics::Drawable;
 use embedded_layout::View;
@@ -20,9 +20,8 @@ where
     T: Clone + Copy + Sized,
 {
     label: &'static str,
-    highlighted: bool,
     position: Point,
-    menu_style: MenuStyle<'a, C>,
+    pub(crate) properties: ItemProperties<'a, C>,
     current_option_index: usize,
     options: &'a [&'static str],
     id: T,
@@ -42,14 +41,78 @@ where
     ) -> MultiOptionItem<'a, C, T> {
         MultiOptionItem {
             label,
-            highlighted: false,
             position: Point::zero(),
-            menu_style,
+            properties: ItemProperties::new(menu_style),
             current_option_index: initial_index,
             options,
             id,
         }
     }
+
+    fn draw_item<D>(&self, display: &mut D, highlighted: bool) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let menu_style = self.properties.style();
+        let item_character_style = menu_style.content_text_style(highlighted);
+        let content_position = menu_style.content_position(self.position);
+        if let Some(icon) = &self.properties.icon() {
+            icon.draw_in_item(display, content_position, highlighted, &menu_style)?;
+        }
+
+        let label_position = label_position(&self.properties.icon(), content_position);
+        Text::with_baseline(
+            self.label,
+            label_position,
+            item_character_style,
+            Baseline::Top,
+        )
+        .draw(display)?;
+        self.properties
+            .draw_description(display, self.label, label_position, highlighted)?;
+
+        let width = display.bounding_box().size().width;
+        let value_top = match self.value_wraps(width) {
+            true => self.size().height as i32,
+            false => 0,
+        };
+        Text::with_text_style(
+            self.display_string(),
+            Point::new(width as i32, value_top),
+            menu_style.content_value_style(highlighted),
+            TextStyleBuilder::new()
+                .alignment(Alignment::Right)
+                .baseline(Baseline::Top)
+                .build(),
+        )
+        .draw(display)?;
+
+        Ok(())
+    }
+}
+
+impl<'a, C, T> MultiOptionItem<'a, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    /// Height of the item when drawn `width` wide, with a second line for the value if it doesn't
+    /// fit beside the label
+    pub fn height_in(&self, width: u32) -> u32 {
+        self.properties.height(self.label, self.value_wraps(width))
+    }
+
+    fn value_wraps(&self, width: u32) -> bool {
+        self.properties.value_wraps(
+            self.label,
+            self.position,
+            text_width(
+                &self.properties.style().value_character_style,
+                self.display_string(),
+            ),
+            width,
+        )
+    }
 }
 
 impl<C, T> MenuItem<T> for MultiOptionItem<'_, C, T>
@@ -92,10 +155,7 @@ where
     }
 
     fn bounds(&self) -> Rectangle {
-        self.menu_style
-            .item_character_style
-            .measure_string(self.label, Point::zero(), Baseline::Bottom)
-            .bounding_box
+        self.properties.bounds(self.label)
     }
 }
 
@@ -110,26 +170,7 @@ where
     where
         D: DrawTarget<Color = Self::Color>,
     {
-        Text::with_baseline(
-            self.label,
-            self.position,
-            self.menu_style.item_character_style,
-            Baseline::Top,
-        )
-        .draw(display)?;
-
-        Text::with_text_style(
-            self.display_string(),
-            Point::new(display.bounding_box().size().width as i32, 0),
-            self.menu_style.item_character_style,
-            TextStyleBuilder::new()
-                .alignment(Alignment::Right)
-                .baseline(Baseline::Top)
-                .build(),
-        )
-        .draw(display)?;
-
-        Ok(())
+        self.draw_item(display, false)
     }
 }
 
@@ -144,40 +185,12 @@ where
     where
         D: DrawTarget<Color = Self::Color>,
     {
-        let highlight_box_style = PrimitiveStyleBuilder::new()
-            .fill_color(self.menu_style.highlight_item_color)
-            .build();
-
-        Rectangle::new(
-            self.position,
-            Size::new(
-                display.bounding_box().size().width,
-                self.menu_style.highlight_text_style.line_height(),
-            ),
-        )
-        .into_styled(highlight_box_style)
-        .draw(display)?;
-
-        Text::with_baseline(
-            self.label,
+        self.properties.style().draw_highlight(
+            display,
             self.position,
-            self.menu_style.highlight_text_style,
-            Baseline::Top,
-        )
-        .draw(display)?;
-
-        Text::with_text_style(
-            self.display_string(),
-            Point::new(display.bounding_box().size().width as i32, 0),
-            self.menu_style.highlight_text_style,
-            TextStyleBuilder::new()
-                .alignment(Alignment::Right)
-                .baseline(Baseline::Top)
-                .build(),
-        )
-        .draw(display)?;
-
-        Ok(())
+            self.height_in(display.bounding_box().size().width),
+        )?;
+        self.draw_item(display, true)
     }
 }
 
@@ -0,0 +1,380 @@
+use crate::icon::label_position;
+use crate::items::field_editor::{draw_fields, wheel_step, FieldCursor};
+use crate::items::properties::ItemProperties;
+use crate::items::{
+    text_width, DrawableHighlighted, EditAction, MenuItem, MenuItemData, SelectedData,
+};
+use crate::text_buffer::TextBuffer;
+use crate::MenuStyle;
+use core::fmt;
+use core::fmt::{Debug, Display, Formatter, Write};
+use core::ops::Range;
+use embedded_graphics::draw_target::DrawTarget;
+use embedded_graphics::geometry::Point;
+use embedded_graphics::pixelcolor::PixelColor;
+use embedded_graphics::primitives::Rectangle;
+use embedded_graphics::text::{Baseline, Text};
+use embedded_graphics::Drawable;
+use embedded_layout::View;
+
+/// Most digits a PIN can have
+pub const MAX_PIN_DIGITS: usize = 8;
+
+/// Code entered into a [`PinItem`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PinCode {
+    digits: [u8; MAX_PIN_DIGITS],
+    len: usize,
+}
+
+impl PinCode {
+    /// The digits entered, first digit first, each from 0 to 9
+    pub fn digits(&self) -> &[u8] {
+        &self.digits[..self.len]
+    }
+}
+
+/// Masked entry of a [`PinCode`] a digit at a time, shared by the items that ask for a code
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct PinEntry {
+    code: PinCode,
+    value_text: TextBuffer<MAX_PIN_DIGITS>,
+    cursor: FieldCursor,
+}
+
+impl PinEntry {
+    pub fn new(digits: usize) -> Self {
+        let mut entry = Self {
+            code: PinCode {
+                digits: [0; MAX_PIN_DIGITS],
+                len: digits.clamp(1, MAX_PIN_DIGITS),
+            },
+            value_text: TextBuffer::new(),
+            cursor: FieldCursor::new(),
+        };
+        entry.update_value_text();
+        entry
+    }
+
+    pub fn digit_count(&self) -> usize {
+        self.code.len
+    }
+
+    pub fn is_editing(&self) -> bool {
+        self.cursor.is_editing()
+    }
+
+    /// Characters of the masked text to draw inverted for the digit being edited
+    pub fn active_field(&self) -> Option<Range<usize>> {
+        self.cursor.field().map(|field| field..field + 1)
+    }
+
+    /// Change the digit under the cursor or move the cursor
+    pub fn edit(&mut self, action: EditAction) {
+        if let Some(field) = self.cursor.apply(action, self.code.len) {
+            let digit = &mut self.code.digits[field];
+            *digit = wheel_step(*digit as u32, 0, 9, action) as u8;
+        }
+        self.update_value_text();
+    }
+
+    /// Start a new entry or step to the next digit. Returns the code once the last digit is
+    /// done, which is then cleared from the entry.
+    pub fn select(&mut self) -> Option<PinCode> {
+        if !self.cursor.is_editing() {
+            self.code.digits = [0; MAX_PIN_DIGITS];
+        }
+        self.cursor.select(self.code.len);
+        let code = self.code;
+        self.update_value_text();
+        if self.cursor.is_editing() {
+            return None;
+        }
+        // Don't keep the code around once it has been handed over
+        self.code.digits = [0; MAX_PIN_DIGITS];
+        Some(code)
+    }
+
+    /// The code masked with '*' except the digit being edited
+    pub fn as_str(&self) -> &str {
+        self.value_text.as_str()
+    }
+
+    fn update_value_text(&mut self) {
+        let mut value_text = TextBuffer::new();
+        for (index, digit) in self.code.digits().iter().enumerate() {
+            let _ = match self.cursor.field() == Some(index) {
+                true => write!(value_text, "{}", digit),
+                false => write!(value_text, "*"),
+            };
+        }
+        self.value_text = value_text;
+    }
+}
+
+/// Entry for a numeric code guarding something such as a service menu. Every digit is masked
+/// with '*' except the one being edited. Selecting the item starts a new entry on the first digit;
+/// up and down change the digit, left and right move between digits, and selecting steps on to
+/// the next digit, finishing after the last. The code is then reported and cleared from the item.
+#[derive(PartialEq, Clone, Copy)]
+pub struct PinItem<'a, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    label: &'static str,
+    position: Point,
+    pub(crate) properties: ItemProperties<'a, C>,
+    entry: PinEntry,
+    id: T,
+}
+
+impl<C, T> PinItem<'_, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    pub fn new<'a>(
+        label: &'static str,
+        id: T,
+        menu_style: MenuStyle<'a, C>,
+        digits: usize,
+    ) -> PinItem<'a, C, T> {
+        PinItem {
+            label,
+            position: Point::zero(),
+            properties: ItemProperties::new(menu_style),
+            entry: PinEntry::new(digits),
+            id,
+        }
+    }
+
+    fn draw_item<D>(&self, display: &mut D, highlighted: bool) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let menu_style = self.properties.style();
+        let item_character_style = menu_style.content_text_style(highlighted);
+        let content_position = menu_style.content_position(self.position);
+        if let Some(icon) = &self.properties.icon() {
+            icon.draw_in_item(display, content_position, highlighted, &menu_style)?;
+        }
+
+        let label_position = label_position(&self.properties.icon(), content_position);
+        Text::with_baseline(
+            self.label,
+            label_position,
+            item_character_style,
+            Baseline::Top,
+        )
+        .draw(display)?;
+        self.properties
+            .draw_description(display, self.label, label_position, highlighted)?;
+
+        let value_top = match self.value_wraps(display.bounding_box().size().width) {
+            true => self.size().height as i32,
+            false => 0,
+        };
+        draw_fields(
+            display,
+            value_top,
+            self.display_string(),
+            self.entry.active_field(),
+            highlighted,
+            &menu_style,
+        )
+    }
+}
+
+impl<'a, C, T> PinItem<'a, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    /// Number of digits in the code
+    pub fn digit_count(&self) -> usize {
+        self.entry.digit_count()
+    }
+
+    pub fn is_editing(&self) -> bool {
+        self.entry.is_editing()
+    }
+
+    /// Change the digit under the cursor or move the cursor. The code is only reported once
+    /// editing finishes.
+    pub fn edit(&mut self, action: EditAction) -> Option<SelectedData<T>> {
+        self.entry.edit(action);
+        None
+    }
+
+    /// Height of the item when drawn `width` wide, with a second line for the value if it doesn't
+    /// fit beside the label
+    pub fn height_in(&self, width: u32) -> u32 {
+        self.properties.height(self.label, self.value_wraps(width))
+    }
+
+    fn value_wraps(&self, width: u32) -> bool {
+        self.properties.value_wraps(
+            self.label,
+            self.position,
+            text_width(
+                &self.properties.style().value_character_style,
+                self.display_string(),
+            ),
+            width,
+        )
+    }
+}
+
+impl<C, T> MenuItem<T> for PinItem<'_, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    fn label(&self) -> &'static str {
+        self.label
+    }
+    fn id(&self) -> T {
+        self.id
+    }
+}
+
+impl<C: PixelColor, T> Debug for PinItem<'_, C, T>
+where
+    T: Clone + Copy + Sized,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "[\"{}\":Pin]", self.label)
+    }
+}
+
+impl<C: PixelColor, T> Display for PinItem<'_, C, T>
+where
+    T: Clone + Copy + Sized,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.label)
+    }
+}
+
+impl<C: PixelColor, T> View for PinItem<'_, C, T>
+where
+    T: Clone + Copy + Sized,
+{
+    fn translate_impl(&mut self, by: Point) {
+        self.position += by;
+    }
+
+    fn bounds(&self) -> Rectangle {
+        self.properties.bounds(self.label)
+    }
+}
+
+impl<C: PixelColor, T> Drawable for PinItem<'_, C, T>
+where
+    T: Clone + Copy + Sized,
+{
+    type Color = C;
+    type Output = ();
+
+    fn draw<D>(&self, display: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        self.draw_item(display, false)
+    }
+}
+
+impl<C: PixelColor, T> DrawableHighlighted for PinItem<'_, C, T>
+where
+    T: Clone + Copy + Sized,
+{
+    type Color = C;
+    type Output = ();
+
+    fn draw_highlighted<D>(&self, display: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        self.properties.style().draw_highlight(
+            display,
+            self.position,
+            self.height_in(display.bounding_box().size().width),
+        )?;
+        self.draw_item(display, true)
+    }
+}
+
+impl<C, T> MenuItemData<T> for PinItem<'_, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    /// Start a new entry or step to the next digit, reporting the code once the last is done
+    fn selected(&mut self) -> SelectedData<T> {
+        // Mid entry the code so far is reported, which the menu ignores while editing
+        let code = self.entry.select().unwrap_or(self.entry.code);
+        SelectedData::Pin { id: self.id, code }
+    }
+
+    fn display_string(&self) -> &str {
+        self.entry.as_str()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::MenuTheme;
+    use crate::MenuStyleBuilder;
+    use embedded_graphics::mono_font::ascii::FONT_6X10;
+    use embedded_graphics::pixelcolor::BinaryColor;
+
+    fn pin_item(digits: usize) -> PinItem<'static, BinaryColor, u8> {
+        let menu_style = MenuStyleBuilder::new(&FONT_6X10, &FONT_6X10, MenuTheme::dark()).build();
+        PinItem::new("PIN", 1, menu_style, digits)
+    }
+
+    #[test]
+    fn only_the_digit_being_edited_is_shown() {
+        let mut item = pin_item(4);
+        assert_eq!(item.display_string(), "****");
+        item.selected();
+        assert_eq!(item.display_string(), "0***");
+        for _ in 0..3 {
+            item.edit(EditAction::Increase);
+        }
+        assert_eq!(item.display_string(), "3***");
+        item.edit(EditAction::Next);
+        item.edit(EditAction::Decrease);
+        assert_eq!(item.display_string(), "*9**");
+    }
+
+    #[test]
+    fn the_code_is_reported_and_cleared_once_the_last_digit_is_done() {
+        let mut item = pin_item(2);
+        item.selected();
+        item.edit(EditAction::Increase);
+        item.selected();
+        item.edit(EditAction::Increase);
+        item.edit(EditAction::Increase);
+        assert!(item.is_editing());
+        let SelectedData::Pin { id: 1, code } = item.selected() else {
+            panic!("a PIN item reports a code");
+        };
+        assert_eq!(code.digits(), &[1, 2]);
+        assert!(!item.is_editing());
+        assert_eq!(item.display_string(), "**");
+        // A new entry starts from zero rather than the code just entered
+        item.selected();
+        assert_eq!(item.display_string(), "0*");
+    }
+
+    #[test]
+    fn codes_have_one_to_max_pin_digits() {
+        assert_eq!(pin_item(0).digit_count(), 1);
+        assert_eq!(pin_item(0).display_string(), "*");
+        assert_eq!(pin_item(MAX_PIN_DIGITS).digit_count(), MAX_PIN_DIGITS);
+        assert_eq!(pin_item(MAX_PIN_DIGITS + 1).digit_count(), MAX_PIN_DIGITS);
+    }
+}
@@ -0,0 +1,309 @@
+use crate::icon::label_position;
+use crate::items::properties::ItemProperties;
+use crate::items::{
+    bar_area, draw_bar, text_width, DrawableHighlighted, MenuItem, MenuItemData, SelectedData,
+    MIN_DRAWN_FONT_HEIGHT,
+};
+use crate::text_buffer::TextBuffer;
+use crate::MenuStyle;
+use core::fmt;
+use core::fmt::{Debug, Display, Formatter};
+use embedded_graphics::draw_target::DrawTarget;
+use embedded_graphics::geometry::Point;
+use embedded_graphics::pixelcolor::PixelColor;
+use embedded_graphics::primitives::Rectangle;
+use embedded_graphics::text::{Alignment, Baseline, Text, TextStyleBuilder};
+use embedded_graphics::Drawable;
+use embedded_layout::View;
+
+/// How far along the work shown by a [`ProgressItem`] is
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Progress {
+    /// Percentage done, capped at 100
+    Percent(u8),
+    /// Still going but with no known end, shown as a block moving along the bar on every
+    /// [`Menu::tick`](crate::Menu::tick)
+    Indeterminate,
+}
+
+/// Width of the moving block of an indeterminate bar, in percent of the bar
+const INDETERMINATE_BLOCK: i64 = 25;
+
+/// Percentage moved by the block of an indeterminate bar on every tick
+const INDETERMINATE_STEP: i64 = 5;
+
+/// Progress bar the application updates while the menu is shown. It can't be highlighted or
+/// selected, like a section.
+#[derive(PartialEq, Clone, Copy)]
+pub struct ProgressItem<'a, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    label: &'static str,
+    position: Point,
+    pub(crate) properties: ItemProperties<'a, C>,
+    progress: Progress,
+    percent_text: TextBuffer<4>,
+    show_percent: bool,
+    ticks: u32,
+    id: T,
+}
+
+impl<C, T> ProgressItem<'_, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    /// Progress item showing the percentage beside the bar
+    pub fn new<'a>(
+        label: &'static str,
+        id: T,
+        menu_style: MenuStyle<'a, C>,
+        progress: Progress,
+    ) -> ProgressItem<'a, C, T> {
+        let mut item = ProgressItem {
+            label,
+            position: Point::zero(),
+            properties: ItemProperties::new(menu_style),
+            progress: Progress::Indeterminate,
+            percent_text: TextBuffer::new(),
+            show_percent: true,
+            ticks: 0,
+            id,
+        };
+        item.set_progress(progress);
+        item
+    }
+
+    fn draw_item<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let menu_style = self.properties.style();
+        let content_position = menu_style.content_position(self.position);
+        if let Some(icon) = &self.properties.icon() {
+            icon.draw_in_item(display, content_position, false, &menu_style)?;
+        }
+
+        let label_position = label_position(&self.properties.icon(), content_position);
+        Text::with_baseline(
+            self.label,
+            label_position,
+            menu_style.item_character_style,
+            Baseline::Top,
+        )
+        .draw(display)?;
+        self.properties
+            .draw_description(display, self.label, label_position, false)?;
+
+        let width = display.bounding_box().size().width;
+        let value_top = match self.value_wraps(width) {
+            true => self.size().height as i32,
+            false => 0,
+        };
+        let value_style = menu_style.value_character_style;
+        let value_text_style = TextStyleBuilder::new()
+            .alignment(Alignment::Right)
+            .baseline(Baseline::Top)
+            .build();
+        let value_color = match value_style.text_color {
+            Some(value_color)
+                if value_style.font.character_size.height >= MIN_DRAWN_FONT_HEIGHT =>
+            {
+                value_color
+            }
+            // Too small to draw a bar, show the percentage instead
+            _ => {
+                Text::with_text_style(
+                    self.display_string(),
+                    Point::new(width as i32, value_top),
+                    value_style,
+                    value_text_style,
+                )
+                .draw(display)?;
+                return Ok(());
+            }
+        };
+
+        let bar_area = bar_area(&value_style, width).translate(Point::new(0, value_top));
+        match self.progress {
+            Progress::Percent(percent) => {
+                draw_bar(display, bar_area, 0, percent as i64, 100, value_color)?;
+            }
+            Progress::Indeterminate => {
+                // The block slides in from the left, across, and out on the right
+                let cycle = 100 + INDETERMINATE_BLOCK;
+                let block_end = (self.ticks as i64 * INDETERMINATE_STEP) % cycle;
+                let block_start = block_end - INDETERMINATE_BLOCK;
+                draw_bar(display, bar_area, block_start, block_end, 100, value_color)?;
+            }
+        }
+
+        if self.show_percent {
+            let gap = value_style.font.character_size.width;
+            Text::with_text_style(
+                self.display_string(),
+                Point::new(bar_area.top_left.x - gap as i32, value_top),
+                value_style,
+                value_text_style,
+            )
+            .draw(display)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, C, T> ProgressItem<'a, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    pub fn set_progress(&mut self, progress: Progress) {
+        self.progress = match progress {
+            Progress::Percent(percent) => Progress::Percent(percent.min(100)),
+            Progress::Indeterminate => Progress::Indeterminate,
+        };
+        self.percent_text = match self.progress {
+            Progress::Percent(percent) => TextBuffer::from_fmt(format_args!("{}%", percent)),
+            Progress::Indeterminate => TextBuffer::new(),
+        };
+    }
+
+    pub fn progress(&self) -> Progress {
+        self.progress
+    }
+
+    /// Show the percentage beside the bar, which is on by default
+    pub fn set_percent_text(&mut self, show_percent: bool) {
+        self.show_percent = show_percent;
+    }
+
+    /// Move the block of an indeterminate bar along. Returns whether the item changed.
+    pub fn tick(&mut self) -> bool {
+        match self.progress {
+            Progress::Indeterminate => {
+                self.ticks = self.ticks.wrapping_add(1);
+                true
+            }
+            Progress::Percent(_) => false,
+        }
+    }
+
+    /// Height of the item when drawn `width` wide, with a second line for the value if it doesn't
+    /// fit beside the label
+    pub fn height_in(&self, width: u32) -> u32 {
+        self.properties.height(self.label, self.value_wraps(width))
+    }
+
+    fn value_wraps(&self, width: u32) -> bool {
+        self.properties
+            .value_wraps(self.label, self.position, self.value_width(width), width)
+    }
+
+    /// Width of the bar and the percentage beside it, or of the percentage alone when the font is
+    /// too small for a bar. Room is kept for "100%" so the row keeps its height as work goes on.
+    fn value_width(&self, width: u32) -> u32 {
+        let value_style = self.properties.style().value_character_style;
+        let percent_width = text_width(&value_style, "100%");
+        if value_style.text_color.is_none()
+            || value_style.font.character_size.height < MIN_DRAWN_FONT_HEIGHT
+        {
+            return percent_width;
+        }
+        let bar_width = bar_area(&value_style, width).size.width;
+        match self.show_percent {
+            true => bar_width + value_style.font.character_size.width + percent_width,
+            false => bar_width,
+        }
+    }
+}
+
+impl<C, T> MenuItem<T> for ProgressItem<'_, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    fn label(&self) -> &'static str {
+        self.label
+    }
+    fn id(&self) -> T {
+        self.id
+    }
+}
+
+impl<C: PixelColor, T> Debug for ProgressItem<'_, C, T>
+where
+    T: Clone + Copy + Sized,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "[\"{}\":Progress]", self.label)
+    }
+}
+
+impl<C: PixelColor, T> Display for ProgressItem<'_, C, T>
+where
+    T: Clone + Copy + Sized,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.label)
+    }
+}
+
+impl<C: PixelColor, T> View for ProgressItem<'_, C, T>
+where
+    T: Clone + Copy + Sized,
+{
+    fn translate_impl(&mut self, by: Point) {
+        self.position += by;
+    }
+
+    fn bounds(&self) -> Rectangle {
+        self.properties.bounds(self.label)
+    }
+}
+
+impl<C: PixelColor, T> Drawable for ProgressItem<'_, C, T>
+where
+    T: Clone + Copy + Sized,
+{
+    type Color = C;
+    type Output = ();
+
+    fn draw<D>(&self, display: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        self.draw_item(display)
+    }
+}
+
+impl<C: PixelColor, T> DrawableHighlighted for ProgressItem<'_, C, T>
+where
+    T: Clone + Copy + Sized,
+{
+    type Color = C;
+    type Output = ();
+
+    fn draw_highlighted<D>(&self, display: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        self.draw_item(display)
+    }
+}
+
+impl<C, T> MenuItemData<T> for ProgressItem<'_, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    fn selected(&mut self) -> SelectedData<T> {
+        SelectedData::Progress { id: self.id }
+    }
+
+    fn display_string(&self) -> &str {
+        self.percent_text.as_str()
+    }
+}
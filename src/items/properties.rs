@@ -0,0 +1,169 @@
+use crate::footer::SoftKeyHints;
+use crate::icon::{bounds_with_icon, label_position, MenuIcon};
+use crate::items::draw_description;
+use crate::{MenuStyle, StyleOverride};
+use embedded_graphics::draw_target::DrawTarget;
+use embedded_graphics::geometry::{AnchorY, Point};
+use embedded_graphics::pixelcolor::PixelColor;
+use embedded_graphics::primitives::Rectangle;
+use embedded_graphics::text::renderer::TextRenderer;
+use embedded_graphics::text::Baseline;
+
+/// Style and optional extras every kind of item carries alongside its own state, set by id
+/// through the [`Menu`](crate::Menu)
+#[derive(PartialEq, Clone, Copy)]
+pub(crate) struct ItemProperties<'a, C>
+where
+    C: PixelColor,
+{
+    menu_style: MenuStyle<'a, C>,
+    icon: Option<MenuIcon<'a, C>>,
+    style_override: Option<StyleOverride<'a, C>>,
+    soft_keys: Option<SoftKeyHints>,
+    description: Option<&'static str>,
+    help_text: Option<&'static str>,
+}
+
+impl<'a, C> ItemProperties<'a, C>
+where
+    C: PixelColor,
+{
+    pub const fn new(menu_style: MenuStyle<'a, C>) -> Self {
+        Self {
+            menu_style,
+            icon: None,
+            style_override: None,
+            soft_keys: None,
+            description: None,
+            help_text: None,
+        }
+    }
+
+    /// Replace the menu wide style the item falls back to where it has no override
+    pub fn set_menu_style(&mut self, menu_style: MenuStyle<'a, C>) {
+        self.menu_style = menu_style;
+    }
+
+    /// The menu wide style with the item's own override applied
+    pub fn style(&self) -> MenuStyle<'a, C> {
+        self.menu_style.with_override(self.style_override)
+    }
+
+    /// Set the icon drawn to the left of the item label
+    pub fn set_icon(&mut self, icon: Option<MenuIcon<'a, C>>) {
+        self.icon = icon;
+    }
+
+    pub fn icon(&self) -> Option<MenuIcon<'a, C>> {
+        self.icon
+    }
+
+    /// Set style changes applied to this item only, on top of the menu wide style
+    pub fn set_style_override(&mut self, style_override: Option<StyleOverride<'a, C>>) {
+        self.style_override = style_override;
+    }
+
+    pub fn style_override(&self) -> Option<StyleOverride<'a, C>> {
+        self.style_override
+    }
+
+    /// Replace the footer soft key hints shown while this item is highlighted
+    pub fn set_soft_keys(&mut self, soft_keys: Option<SoftKeyHints>) {
+        self.soft_keys = soft_keys;
+    }
+
+    pub fn soft_keys(&self) -> Option<SoftKeyHints> {
+        self.soft_keys
+    }
+
+    /// Set a line of explanation drawn in the description style beneath the label
+    pub fn set_description(&mut self, description: Option<&'static str>) {
+        self.description = description;
+    }
+
+    pub fn description(&self) -> Option<&'static str> {
+        self.description
+    }
+
+    /// Set the longer explanation shown in the help panel or on the help page
+    pub fn set_help_text(&mut self, help_text: Option<&'static str>) {
+        self.help_text = help_text;
+    }
+
+    pub fn help_text(&self) -> Option<&'static str> {
+        self.help_text
+    }
+
+    /// Bounds of `label` and the icon, without the description
+    pub fn first_line_bounds(&self, label: &str) -> Rectangle {
+        let label_bounds = self
+            .style()
+            .item_character_style
+            .measure_string(label, Point::zero(), Baseline::Bottom)
+            .bounding_box;
+        bounds_with_icon(&self.icon, label_bounds)
+    }
+
+    /// Bounds of an item showing `label`, with the description beneath it
+    pub fn bounds(&self, label: &str) -> Rectangle {
+        self.with_description(self.first_line_bounds(label))
+    }
+
+    /// Grow the bounds of an item's first line to include the description drawn beneath it
+    pub fn with_description(&self, first_line_bounds: Rectangle) -> Rectangle {
+        match self.description {
+            Some(_) => first_line_bounds.resized_height(
+                first_line_bounds.size.height
+                    + self.style().description_character_style.line_height(),
+                AnchorY::Top,
+            ),
+            None => first_line_bounds,
+        }
+    }
+
+    /// Draw the description, if there is one, beneath `label` drawn at `label_position`
+    pub fn draw_description<D>(
+        &self,
+        display: &mut D,
+        label: &str,
+        label_position: Point,
+        highlighted: bool,
+    ) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        draw_description(
+            display,
+            self.description,
+            label_position + Point::new(0, self.first_line_bounds(label).size.height as i32),
+            highlighted,
+            &self.style(),
+        )
+    }
+
+    /// Whether a value `value_width` wide drawn right-aligned on the line of `label`, in an item
+    /// at `position` that is `width` wide, would overlap the label, in which case the value goes
+    /// on a second line
+    pub fn value_wraps(&self, label: &str, position: Point, value_width: u32, width: u32) -> bool {
+        let menu_style = self.style();
+        let label_end = menu_style
+            .item_character_style
+            .measure_string(
+                label,
+                label_position(&self.icon, menu_style.content_position(position)),
+                Baseline::Top,
+            )
+            .next_position;
+        let gap = menu_style.value_character_style.font.character_size.width as i32;
+        label_end.x + gap + value_width as i32 > width as i32
+    }
+
+    /// Height of an item showing `label`, with a second line for the value if it wraps
+    pub fn height(&self, label: &str, value_wraps: bool) -> u32 {
+        let height = self.bounds(label).size.height;
+        match value_wraps {
+            true => height + self.style().value_character_style.line_height(),
+            false => height,
+        }
+    }
+}
@@ -0,0 +1,270 @@
+use crate::icon::label_position;
+use crate::items::properties::ItemProperties;
+use crate::items::{
+    text_width, DrawableHighlighted, MenuItem, MenuItemData, SelectedData, MIN_DRAWN_FONT_HEIGHT,
+};
+use crate::MenuStyle;
+use core::fmt;
+use core::fmt::{Debug, Display, Formatter};
+use embedded_graphics::draw_target::DrawTarget;
+use embedded_graphics::geometry::Point;
+use embedded_graphics::pixelcolor::PixelColor;
+use embedded_graphics::prelude::Primitive;
+use embedded_graphics::primitives::{Circle, PrimitiveStyle, Rectangle};
+use embedded_graphics::text::{Alignment, Baseline, Text, TextStyleBuilder};
+use embedded_graphics::Drawable;
+use embedded_layout::View;
+
+/// One option of a radio group. Every option of the group is its own row sharing the group id,
+/// and exactly one of them is marked as chosen.
+#[derive(PartialEq, Clone, Copy)]
+pub struct RadioItem<'a, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    position: Point,
+    pub(crate) properties: ItemProperties<'a, C>,
+    options: &'a [&'static str],
+    option_index: usize,
+    chosen: bool,
+    id: T,
+}
+
+impl<C, T> RadioItem<'_, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    /// Row for `options[option_index]` of the radio group `id`
+    pub const fn new<'a>(
+        id: T,
+        menu_style: MenuStyle<'a, C>,
+        options: &'a [&'static str],
+        option_index: usize,
+        chosen: bool,
+    ) -> RadioItem<'a, C, T> {
+        RadioItem {
+            position: Point::zero(),
+            properties: ItemProperties::new(menu_style),
+            options,
+            option_index,
+            chosen,
+            id,
+        }
+    }
+
+    fn draw_item<D>(&self, display: &mut D, highlighted: bool) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let menu_style = self.properties.style();
+        let item_character_style = menu_style.content_text_style(highlighted);
+        let content_position = menu_style.content_position(self.position);
+        if let Some(icon) = &self.properties.icon() {
+            icon.draw_in_item(display, content_position, highlighted, &menu_style)?;
+        }
+
+        let label_position = label_position(&self.properties.icon(), content_position);
+        Text::with_baseline(
+            self.label(),
+            label_position,
+            item_character_style,
+            Baseline::Top,
+        )
+        .draw(display)?;
+        self.properties
+            .draw_description(display, self.label(), label_position, highlighted)?;
+
+        let width = display.bounding_box().size().width;
+        let value_top = match self.value_wraps(width) {
+            true => self.size().height as i32,
+            false => 0,
+        };
+        let width = width as i32;
+        let value_style = menu_style.content_value_style(highlighted);
+        let dot_size = value_style.font.character_size.height.saturating_sub(2);
+        match value_style.text_color {
+            Some(value_color)
+                if value_style.font.character_size.height >= MIN_DRAWN_FONT_HEIGHT =>
+            {
+                let outline =
+                    Circle::new(Point::new(width - dot_size as i32, value_top + 1), dot_size);
+                outline
+                    .into_styled(PrimitiveStyle::with_stroke(value_color, 1))
+                    .draw(display)?;
+                if self.chosen {
+                    Circle::with_center(outline.center(), dot_size.saturating_sub(4))
+                        .into_styled(PrimitiveStyle::with_fill(value_color))
+                        .draw(display)?;
+                }
+            }
+            _ => {
+                Text::with_text_style(
+                    self.display_string(),
+                    Point::new(width, value_top),
+                    value_style,
+                    TextStyleBuilder::new()
+                        .alignment(Alignment::Right)
+                        .baseline(Baseline::Top)
+                        .build(),
+                )
+                .draw(display)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, C, T> RadioItem<'a, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    /// Index of the option this row stands for
+    pub fn option_index(&self) -> usize {
+        self.option_index
+    }
+
+    /// Whether this row is the chosen option of its group
+    pub fn is_chosen(&self) -> bool {
+        self.chosen
+    }
+
+    /// Whether this row, `offset` rows below the first row of the group of `other`, belongs to that
+    /// group. The rows of a group follow each other in option order, which also tells apart next
+    /// groups built from the same options.
+    pub(crate) fn same_group(&self, other: &RadioItem<'_, C, T>, offset: usize) -> bool {
+        core::ptr::eq(self.options, other.options) && self.option_index == offset
+    }
+
+    /// Mark this row as chosen when it stands for `option_index`, and clear it otherwise
+    pub(crate) fn choose(&mut self, option_index: usize) {
+        self.chosen = self.option_index == option_index;
+    }
+
+    /// Height of the item when drawn `width` wide, with a second line for the value if it doesn't
+    /// fit beside the label
+    pub fn height_in(&self, width: u32) -> u32 {
+        self.properties
+            .height(self.label(), self.value_wraps(width))
+    }
+
+    fn value_wraps(&self, width: u32) -> bool {
+        self.properties
+            .value_wraps(self.label(), self.position, self.value_width(), width)
+    }
+
+    /// Width of the drawn dot, or of the text standing in for it in fonts too small to draw it
+    fn value_width(&self) -> u32 {
+        let value_style = self.properties.style().value_character_style;
+        match value_style.text_color {
+            Some(_) if value_style.font.character_size.height >= MIN_DRAWN_FONT_HEIGHT => {
+                value_style.font.character_size.height.saturating_sub(2)
+            }
+            _ => text_width(&value_style, self.display_string()),
+        }
+    }
+}
+
+impl<C, T> MenuItem<T> for RadioItem<'_, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    fn label(&self) -> &'static str {
+        self.options.get(self.option_index).copied().unwrap_or("")
+    }
+    fn id(&self) -> T {
+        self.id
+    }
+}
+
+impl<C: PixelColor, T> Debug for RadioItem<'_, C, T>
+where
+    T: Clone + Copy + Sized,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "[\"{}\":Radio]", self.label())
+    }
+}
+
+impl<C: PixelColor, T> Display for RadioItem<'_, C, T>
+where
+    T: Clone + Copy + Sized,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.label())
+    }
+}
+
+impl<C: PixelColor, T> View for RadioItem<'_, C, T>
+where
+    T: Clone + Copy + Sized,
+{
+    fn translate_impl(&mut self, by: Point) {
+        self.position += by;
+    }
+
+    fn bounds(&self) -> Rectangle {
+        self.properties.bounds(self.label())
+    }
+}
+
+impl<C: PixelColor, T> Drawable for RadioItem<'_, C, T>
+where
+    T: Clone + Copy + Sized,
+{
+    type Color = C;
+    type Output = ();
+
+    fn draw<D>(&self, display: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        self.draw_item(display, false)
+    }
+}
+
+impl<C: PixelColor, T> DrawableHighlighted for RadioItem<'_, C, T>
+where
+    T: Clone + Copy + Sized,
+{
+    type Color = C;
+    type Output = ();
+
+    fn draw_highlighted<D>(&self, display: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        self.properties.style().draw_highlight(
+            display,
+            self.position,
+            self.height_in(display.bounding_box().size().width),
+        )?;
+        self.draw_item(display, true)
+    }
+}
+
+impl<C, T> MenuItemData<T> for RadioItem<'_, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    /// Choose this row. The menu clears the rest of the group.
+    fn selected(&mut self) -> SelectedData<T> {
+        self.chosen = true;
+        SelectedData::MultiOption {
+            id: self.id,
+            option_id: self.option_index,
+        }
+    }
+
+    fn display_string(&self) -> &str {
+        match self.chosen {
+            true => "(*)",
+            false => "( )",
+        }
+    }
+}
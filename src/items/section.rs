@@ -1,12 +1,14 @@
+use crate::icon::label_position;
+use crate::items::properties::ItemProperties;
 use crate::items::{DrawableHighlighted, MenuItem, MenuItemData, SelectedData};
 use crate::MenuStyle;
 use core::fmt;
 use core::fmt::{Debug, Display, Formatter};
 use embedded_graphics::draw_target::DrawTarget;
-use embedded_graphics::geometry::Point;
+use embedded_graphics::geometry::{Dimensions, Point};
 use embedded_graphics::pixelcolor::PixelColor;
-use embedded_graphics::primitives::Rectangle;
-use embedded_graphics::text::renderer::TextRenderer;
+use embedded_graphics::prelude::Primitive;
+use embedded_graphics::primitives::{Line, PrimitiveStyle, Rectangle};
 use embedded_graphics::text::{Alignment, Baseline, Text, TextStyleBuilder};
 use embedded_graphics::Drawable;
 use embedded_layout::View;
@@ -17,9 +19,8 @@ where
     C: PixelColor,
 {
     label: &'static str,
-    highlighted: bool,
     position: Point,
-    menu_style: MenuStyle<'a, C>,
+    pub(crate) properties: ItemProperties<'a, C>,
     id: T,
 }
 
@@ -35,14 +36,20 @@ where
     ) -> SectionItem<'a, C, T> {
         SectionItem {
             label,
-            highlighted: false,
             position: Point::zero(),
-            menu_style,
+            properties: ItemProperties::new(menu_style),
             id,
         }
     }
 }
 
+impl<'a, C, T> SectionItem<'a, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+}
+
 impl<C, T> MenuItem<T> for SectionItem<'_, C, T>
 where
     C: PixelColor,
@@ -80,10 +87,7 @@ where
     }
 
     fn bounds(&self) -> Rectangle {
-        self.menu_style
-            .item_character_style
-            .measure_string(self.label, Point::zero(), Baseline::Bottom)
-            .bounding_box
+        self.properties.bounds(self.label)
     }
 }
 
@@ -98,16 +102,48 @@ where
     where
         D: DrawTarget<Color = Self::Color>,
     {
-        Text::with_text_style(
+        let menu_style = self.properties.style();
+        if let Some(icon) = &self.properties.icon() {
+            icon.draw(display, menu_style.content_position(self.position))?;
+        }
+
+        let display_width = display.bounding_box().size().width as i32;
+        let label = Text::with_text_style(
             self.label,
-            Point::new(display.bounding_box().size().width as i32 / 2, 0),
-            self.menu_style.item_character_style,
+            Point::new(display_width / 2, 0),
+            menu_style.disabled_character_style,
             TextStyleBuilder::new()
                 .alignment(Alignment::Center)
                 .baseline(Baseline::Top)
                 .build(),
-        )
-        .draw(display)?;
+        );
+        let label_bounds = label.bounding_box();
+        label.draw(display)?;
+
+        if let Some(separator_color) = menu_style.separator_color {
+            let separator_gap = 2;
+            let separator_y = label_bounds.center().y;
+            let separator_style = PrimitiveStyle::with_stroke(separator_color, 1);
+            let left_start = label_position(
+                &self.properties.icon(),
+                menu_style.content_position(self.position),
+            );
+            Line::new(
+                Point::new(left_start.x, separator_y),
+                Point::new(label_bounds.top_left.x - separator_gap, separator_y),
+            )
+            .into_styled(separator_style)
+            .draw(display)?;
+            Line::new(
+                Point::new(
+                    label_bounds.top_left.x + label_bounds.size.width as i32 + separator_gap,
+                    separator_y,
+                ),
+                Point::new(display_width - 1, separator_y),
+            )
+            .into_styled(separator_style)
+            .draw(display)?;
+        }
 
         Ok(())
     }
@@ -0,0 +1,408 @@
+use crate::icon::label_position;
+use crate::items::properties::ItemProperties;
+use crate::items::{
+    bar_area, draw_bar, text_width, DrawableHighlighted, EditAction, MenuItem, MenuItemData,
+    SelectedData, MIN_DRAWN_FONT_HEIGHT,
+};
+use crate::text_buffer::TextBuffer;
+use crate::MenuStyle;
+use core::fmt;
+use core::fmt::{Debug, Display, Formatter};
+use embedded_graphics::draw_target::DrawTarget;
+use embedded_graphics::geometry::Point;
+use embedded_graphics::pixelcolor::PixelColor;
+use embedded_graphics::primitives::Rectangle;
+use embedded_graphics::text::{Alignment, Baseline, Text, TextStyleBuilder};
+use embedded_graphics::Drawable;
+use embedded_layout::View;
+
+/// When a slider reports its value while it is being edited
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SliderUpdates {
+    /// Only when editing is finished with select
+    OnCommit,
+    /// After every step as well, collected with
+    /// [`Menu::take_edit_change`](crate::Menu::take_edit_change)
+    EveryChange,
+}
+
+/// Longest value text, "-2147483648"
+const VALUE_TEXT_LEN: usize = 11;
+
+/// Value within a range drawn as a bar on the right of the row. Selecting the item starts
+/// editing, where navigating steps the value, and selecting again finishes.
+#[derive(PartialEq, Clone, Copy)]
+pub struct SliderItem<'a, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    label: &'static str,
+    position: Point,
+    pub(crate) properties: ItemProperties<'a, C>,
+    min: i32,
+    max: i32,
+    step: i32,
+    value: i32,
+    value_text: TextBuffer<VALUE_TEXT_LEN>,
+    updates: SliderUpdates,
+    editing: bool,
+    id: T,
+}
+
+impl<C, T> SliderItem<'_, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    /// Slider between `min` and `max` inclusive, stepping by 1 and reporting on commit
+    pub fn new<'a>(
+        label: &'static str,
+        id: T,
+        menu_style: MenuStyle<'a, C>,
+        min: i32,
+        max: i32,
+        initial_value: i32,
+    ) -> SliderItem<'a, C, T> {
+        let mut item = SliderItem {
+            label,
+            position: Point::zero(),
+            properties: ItemProperties::new(menu_style),
+            min: min.min(max),
+            max: max.max(min),
+            step: 1,
+            value: 0,
+            value_text: TextBuffer::new(),
+            updates: SliderUpdates::OnCommit,
+            editing: false,
+            id,
+        };
+        item.set_value(initial_value);
+        item
+    }
+
+    fn draw_item<D>(&self, display: &mut D, highlighted: bool) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let menu_style = self.properties.style();
+        let item_character_style = menu_style.content_text_style(highlighted);
+        let content_position = menu_style.content_position(self.position);
+        if let Some(icon) = &self.properties.icon() {
+            icon.draw_in_item(display, content_position, highlighted, &menu_style)?;
+        }
+
+        let label_position = label_position(&self.properties.icon(), content_position);
+        Text::with_baseline(
+            self.label,
+            label_position,
+            item_character_style,
+            Baseline::Top,
+        )
+        .draw(display)?;
+        self.properties
+            .draw_description(display, self.label, label_position, highlighted)?;
+
+        let width = display.bounding_box().size().width;
+        let value_top = match self.value_wraps(width) {
+            true => self.size().height as i32,
+            false => 0,
+        };
+        let value_style = menu_style.content_value_style(highlighted);
+        let value_text_style = TextStyleBuilder::new()
+            .alignment(Alignment::Right)
+            .baseline(Baseline::Top)
+            .build();
+        let value_color = match value_style.text_color {
+            Some(value_color)
+                if value_style.font.character_size.height >= MIN_DRAWN_FONT_HEIGHT =>
+            {
+                value_color
+            }
+            // Too small to draw a bar, show the number instead
+            _ => {
+                Text::with_text_style(
+                    self.display_string(),
+                    Point::new(width as i32, value_top),
+                    value_style,
+                    value_text_style,
+                )
+                .draw(display)?;
+                return Ok(());
+            }
+        };
+
+        let bar_area = bar_area(&value_style, width).translate(Point::new(0, value_top));
+        draw_bar(
+            display,
+            bar_area,
+            0,
+            self.value as i64 - self.min as i64,
+            self.max as i64 - self.min as i64,
+            value_color,
+        )?;
+
+        // The exact value is shown beside the bar while it is being adjusted
+        if self.editing {
+            let gap = value_style.font.character_size.width;
+            Text::with_text_style(
+                self.display_string(),
+                Point::new(bar_area.top_left.x - gap as i32, value_top),
+                value_style,
+                value_text_style,
+            )
+            .draw(display)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, C, T> SliderItem<'a, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    /// Set the value, kept within the range of the slider
+    pub fn set_value(&mut self, value: i32) {
+        self.value = value.clamp(self.min, self.max);
+        self.value_text = TextBuffer::from_fmt(format_args!("{}", self.value));
+    }
+
+    pub fn value(&self) -> i32 {
+        self.value
+    }
+
+    /// Set how much each step changes the value while editing
+    pub fn set_step(&mut self, step: i32) {
+        self.step = step.max(1);
+    }
+
+    /// Set whether the value is reported on every step or only when editing finishes
+    pub fn set_updates(&mut self, updates: SliderUpdates) {
+        self.updates = updates;
+    }
+
+    pub fn is_editing(&self) -> bool {
+        self.editing
+    }
+
+    /// Step the value while editing. Returns the new value if it changed and the slider reports
+    /// every change.
+    pub fn edit(&mut self, action: EditAction) -> Option<SelectedData<T>> {
+        let previous_value = self.value;
+        match action {
+            EditAction::Increase | EditAction::Next => {
+                self.set_value(self.value.saturating_add(self.step))
+            }
+            EditAction::Decrease | EditAction::Previous => {
+                self.set_value(self.value.saturating_sub(self.step))
+            }
+        }
+        match (self.updates, self.value != previous_value) {
+            (SliderUpdates::EveryChange, true) => Some(self.selected_data()),
+            _ => None,
+        }
+    }
+
+    fn selected_data(&self) -> SelectedData<T> {
+        SelectedData::Slider {
+            id: self.id,
+            value: self.value,
+        }
+    }
+
+    /// Height of the item when drawn `width` wide, with a second line for the value if it doesn't
+    /// fit beside the label
+    pub fn height_in(&self, width: u32) -> u32 {
+        self.properties.height(self.label, self.value_wraps(width))
+    }
+
+    fn value_wraps(&self, width: u32) -> bool {
+        self.properties
+            .value_wraps(self.label, self.position, self.value_width(width), width)
+    }
+
+    /// Width of the bar, or of the longest value text when the font is too small for a bar. The
+    /// exact value drawn beside the bar while editing is left out, so the row keeps its height as
+    /// editing starts and stops.
+    fn value_width(&self, width: u32) -> u32 {
+        let value_style = self.properties.style().value_character_style;
+        if value_style.text_color.is_some()
+            && value_style.font.character_size.height >= MIN_DRAWN_FONT_HEIGHT
+        {
+            return bar_area(&value_style, width).size.width;
+        }
+        [self.min, self.max]
+            .iter()
+            .map(|value| {
+                let value_text = TextBuffer::<VALUE_TEXT_LEN>::from_fmt(format_args!("{}", value));
+                text_width(&value_style, value_text.as_str())
+            })
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+impl<C, T> MenuItem<T> for SliderItem<'_, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    fn label(&self) -> &'static str {
+        self.label
+    }
+    fn id(&self) -> T {
+        self.id
+    }
+}
+
+impl<C: PixelColor, T> Debug for SliderItem<'_, C, T>
+where
+    T: Clone + Copy + Sized,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "[\"{}\":Slider]", self.label)
+    }
+}
+
+impl<C: PixelColor, T> Display for SliderItem<'_, C, T>
+where
+    T: Clone + Copy + Sized,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.label)
+    }
+}
+
+impl<C: PixelColor, T> View for SliderItem<'_, C, T>
+where
+    T: Clone + Copy + Sized,
+{
+    fn translate_impl(&mut self, by: Point) {
+        self.position += by;
+    }
+
+    fn bounds(&self) -> Rectangle {
+        self.properties.bounds(self.label)
+    }
+}
+
+impl<C: PixelColor, T> Drawable for SliderItem<'_, C, T>
+where
+    T: Clone + Copy + Sized,
+{
+    type Color = C;
+    type Output = ();
+
+    fn draw<D>(&self, display: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        self.draw_item(display, false)
+    }
+}
+
+impl<C: PixelColor, T> DrawableHighlighted for SliderItem<'_, C, T>
+where
+    T: Clone + Copy + Sized,
+{
+    type Color = C;
+    type Output = ();
+
+    fn draw_highlighted<D>(&self, display: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        self.properties.style().draw_highlight(
+            display,
+            self.position,
+            self.height_in(display.bounding_box().size().width),
+        )?;
+        self.draw_item(display, true)
+    }
+}
+
+impl<C, T> MenuItemData<T> for SliderItem<'_, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    /// Start editing, or finish and report the value when already editing
+    fn selected(&mut self) -> SelectedData<T> {
+        self.editing = !self.editing;
+        self.selected_data()
+    }
+
+    fn display_string(&self) -> &str {
+        self.value_text.as_str()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::MenuTheme;
+    use crate::MenuStyleBuilder;
+    use embedded_graphics::mono_font::ascii::FONT_6X10;
+    use embedded_graphics::pixelcolor::BinaryColor;
+
+    fn slider(value: i32) -> SliderItem<'static, BinaryColor, u8> {
+        let menu_style = MenuStyleBuilder::new(&FONT_6X10, &FONT_6X10, MenuTheme::dark()).build();
+        SliderItem::new("Volume", 1, menu_style, 0, 10, value)
+    }
+
+    #[test]
+    fn selecting_starts_and_finishes_editing() {
+        let mut item = slider(5);
+        assert!(!item.is_editing());
+        item.selected();
+        assert!(item.is_editing());
+        item.edit(EditAction::Increase);
+        item.edit(EditAction::Next);
+        item.edit(EditAction::Decrease);
+        item.edit(EditAction::Increase);
+        assert_eq!(item.selected(), SelectedData::Slider { id: 1, value: 7 });
+        assert!(!item.is_editing());
+    }
+
+    #[test]
+    fn steps_stay_within_the_range() {
+        let mut item = slider(9);
+        item.set_step(3);
+        item.selected();
+        item.edit(EditAction::Increase);
+        assert_eq!(item.value(), 10);
+        for _ in 0..5 {
+            item.edit(EditAction::Decrease);
+        }
+        assert_eq!(item.value(), 0);
+        assert_eq!(item.display_string(), "0");
+    }
+
+    #[test]
+    fn values_are_reported_on_commit_by_default() {
+        let mut item = slider(5);
+        item.selected();
+        assert_eq!(item.edit(EditAction::Increase), None);
+        assert_eq!(item.edit(EditAction::Increase), None);
+        assert_eq!(item.selected(), SelectedData::Slider { id: 1, value: 7 });
+    }
+
+    #[test]
+    fn every_change_reports_each_step_that_moves_the_value() {
+        let mut item = slider(9);
+        item.set_updates(SliderUpdates::EveryChange);
+        item.selected();
+        assert_eq!(
+            item.edit(EditAction::Increase),
+            Some(SelectedData::Slider { id: 1, value: 10 })
+        );
+        // Already at the top, so nothing changed to report
+        assert_eq!(item.edit(EditAction::Increase), None);
+        assert_eq!(
+            item.edit(EditAction::Decrease),
+            Some(SelectedData::Slider { id: 1, value: 9 })
+        );
+    }
+}
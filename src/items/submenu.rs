@@ -1,32 +1,195 @@
-use crate::items::{DrawableHighlighted, MenuItem, MenuItemData, SelectedData};
-use crate::MenuStyle;
+use crate::icon::label_position;
+use crate::items::custom::NoCustomItems;
+use crate::items::field_editor::draw_fields;
+use crate::items::pin::{PinCode, PinEntry, MAX_PIN_DIGITS};
+use crate::items::properties::ItemProperties;
+use crate::items::{
+    draw_description, draw_indicator, DrawableHighlighted, EditAction, IndicatorDirection,
+    MenuItem, MenuItemData, SelectedData,
+};
+use crate::layout::MenuLayout;
+use crate::{Menu, MenuStyle};
 use core::fmt;
 use core::fmt::{Debug, Display, Formatter};
 use embedded_graphics::draw_target::{DrawTarget, DrawTargetExt};
-use embedded_graphics::geometry::{AnchorX, Point, Size};
-use embedded_graphics::mono_font::MonoTextStyle;
+use embedded_graphics::geometry::Point;
 use embedded_graphics::pixelcolor::PixelColor;
-use embedded_graphics::prelude::Primitive;
-use embedded_graphics::primitives::{PrimitiveStyle, PrimitiveStyleBuilder, Rectangle, Triangle};
-use embedded_graphics::text::renderer::TextRenderer;
+use embedded_graphics::primitives::Rectangle;
 use embedded_graphics::text::{Baseline, Text};
 use embedded_graphics::Drawable;
 use embedded_layout::View;
 
+/// What a locked [`SubmenuItem`] asks for before the menu switches to it, made with
+/// [`passcode`](Self::passcode), [`check`](Self::check) or [`grant`](Self::grant)
+#[derive(Clone, Copy)]
+pub struct SubmenuLock<'a>(LockKind<'a>);
+
+#[derive(Clone, Copy)]
+enum LockKind<'a> {
+    /// Open with this code, each digit from 0 to 9
+    Passcode(&'a [u8]),
+    /// Ask for a code with this many digits and let the application decide whether it opens
+    Check {
+        digits: usize,
+        check: &'a dyn Fn(&PinCode) -> bool,
+    },
+    /// Let the application decide whether it opens, without asking for a code
+    Grant(&'a dyn Fn() -> bool),
+}
+
+impl<'a> SubmenuLock<'a> {
+    /// Lock opening with `passcode`, or `None` if it could never be entered as it needs 1 to
+    /// [`MAX_PIN_DIGITS`] digits from 0 to 9
+    pub const fn passcode(passcode: &'a [u8]) -> Option<Self> {
+        let mut index = 0;
+        while index < passcode.len() {
+            if passcode[index] > 9 {
+                return None;
+            }
+            index += 1;
+        }
+        match Self::entered_digits(passcode.len()) {
+            true => Some(SubmenuLock(LockKind::Passcode(passcode))),
+            false => None,
+        }
+    }
+
+    /// Lock asking for `digits` digits and opening when `check` accepts them, or `None` if
+    /// `digits` isn't 1 to [`MAX_PIN_DIGITS`]
+    pub const fn check(digits: usize, check: &'a dyn Fn(&PinCode) -> bool) -> Option<Self> {
+        match Self::entered_digits(digits) {
+            true => Some(SubmenuLock(LockKind::Check { digits, check })),
+            false => None,
+        }
+    }
+
+    /// Lock opening on select whenever `grant` allows it, such as while a key switch is turned
+    pub const fn grant(grant: &'a dyn Fn() -> bool) -> Self {
+        SubmenuLock(LockKind::Grant(grant))
+    }
+
+    /// Whether a code this many digits long fits the entry
+    const fn entered_digits(digits: usize) -> bool {
+        digits >= 1 && digits <= MAX_PIN_DIGITS
+    }
+
+    /// Length of the code asked for, if there is one
+    fn digits(&self) -> Option<usize> {
+        match self.0 {
+            LockKind::Passcode(passcode) => Some(passcode.len()),
+            LockKind::Check { digits, .. } => Some(digits),
+            LockKind::Grant(_) => None,
+        }
+    }
+
+    /// Whether the lock opens with `code`, entered if the lock asks for one
+    fn opens_with(&self, code: Option<&PinCode>) -> bool {
+        match (self.0, code) {
+            (LockKind::Passcode(passcode), Some(code)) => code.digits() == passcode,
+            (LockKind::Check { check, .. }, Some(code)) => check(code),
+            (LockKind::Grant(grant), _) => grant(),
+            _ => false,
+        }
+    }
+}
+
+impl PartialEq for SubmenuLock<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.0, other.0) {
+            (LockKind::Passcode(passcode), LockKind::Passcode(other_passcode)) => {
+                passcode == other_passcode
+            }
+            (
+                LockKind::Check { digits, check },
+                LockKind::Check {
+                    digits: other_digits,
+                    check: other_check,
+                },
+            ) => digits == other_digits && core::ptr::addr_eq(check, other_check),
+            (LockKind::Grant(grant), LockKind::Grant(other_grant)) => {
+                core::ptr::addr_eq(grant, other_grant)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Debug for SubmenuLock<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        // Keep the passcode itself out of logs
+        match self.0 {
+            LockKind::Passcode(_) => write!(f, "Passcode"),
+            LockKind::Check { digits, .. } => write!(f, "Check {{ digits: {} }}", digits),
+            LockKind::Grant(_) => write!(f, "Grant"),
+        }
+    }
+}
+
+/// Callback adding the items of a submenu as it is entered, see [`SubmenuItem::set_populate`]
+pub type PopulateFn<'a, C, T, X = NoCustomItems<C>> = dyn Fn(&mut Menu<'a, C, T, X>) + 'a;
+
+/// Populate callback of a submenu, compared by identity
+struct Populate<'a, C, T, X>(&'a PopulateFn<'a, C, T, X>)
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized;
+
+impl<C, T, X> Clone for Populate<'_, C, T, X>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C, T, X> Copy for Populate<'_, C, T, X>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+}
+
+impl<C, T, X> PartialEq for Populate<'_, C, T, X>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    fn eq(&self, other: &Self) -> bool {
+        core::ptr::addr_eq(self.0, other.0)
+    }
+}
+
+/// Shown beside a locked submenu after a wrong code
+const WRONG_CODE_MESSAGE: &str = "Wrong code";
+
+/// Shown beside a submenu locked by [`SubmenuLock::grant`] when it refuses to open
+const DENIED_MESSAGE: &str = "Denied";
+
+/// Shown beside a locked submenu once it has run out of attempts
+const LOCKED_OUT_MESSAGE: &str = "Locked";
+
 #[derive(PartialEq, Clone, Copy)]
-pub struct SubmenuItem<'a, C, T>
+pub struct SubmenuItem<'a, C, T, X = NoCustomItems<C>>
 where
     C: PixelColor,
     T: Clone + Copy + Sized,
 {
     label: &'static str,
-    highlighted: bool,
     position: Point,
-    menu_style: MenuStyle<'a, C>,
+    pub(crate) properties: ItemProperties<'a, C>,
+    layout: MenuLayout,
+    lock: Option<SubmenuLock<'a>>,
+    max_attempts: Option<u32>,
+    failed_attempts: u32,
+    entry: Option<PinEntry>,
+    message: Option<&'static str>,
+    populate: Option<Populate<'a, C, T, X>>,
     id: T,
 }
 
-impl<C, T> SubmenuItem<'_, C, T>
+impl<C, T, X> SubmenuItem<'_, C, T, X>
 where
     C: PixelColor,
     T: Clone + Copy + Sized,
@@ -35,69 +198,164 @@ where
         label: &'static str,
         id: T,
         menu_style: MenuStyle<'a, C>,
-    ) -> SubmenuItem<'a, C, T> {
+    ) -> SubmenuItem<'a, C, T, X> {
         SubmenuItem {
             label,
-            highlighted: false,
             position: Point::zero(),
-            menu_style,
+            properties: ItemProperties::new(menu_style),
+            layout: MenuLayout::List,
+            lock: None,
+            max_attempts: None,
+            failed_attempts: 0,
+            entry: None,
+            message: None,
+            populate: None,
             id,
         }
     }
 
-    fn draw_item<D>(
-        &self,
-        display: &mut D,
-        indicator_fill_color: C,
-        item_character_style: MonoTextStyle<'_, C>,
-    ) -> Result<(), D::Error>
+    fn draw_item<D>(&self, display: &mut D, highlighted: bool) -> Result<(), D::Error>
     where
         D: DrawTarget<Color = C>,
     {
-        let indicator_vertical_pad = 2u32;
-        let indicator_right_pad = 2u32;
-        let submenu_indicator_size = Size::new(self.size().height / 2, self.size().height);
-
-        let display_size = display.bounding_box();
-        let submenu_indicator_draw_area =
-            display_size.resized_width(submenu_indicator_size.width, AnchorX::Right);
-        let mut indicator_display = display.cropped(&submenu_indicator_draw_area);
-        let filled_style = PrimitiveStyle::with_fill(indicator_fill_color);
-
-        Triangle::new(
-            Point::new(0, indicator_vertical_pad as i32),
-            Point::new(
-                0,
-                (submenu_indicator_size.height - indicator_vertical_pad) as i32,
-            ),
-            Point::new(
-                (submenu_indicator_size.width - indicator_right_pad) as i32,
-                (((submenu_indicator_size.height - indicator_vertical_pad * 2) / 2)
-                    + indicator_vertical_pad) as i32,
-            ),
-        )
-        .into_styled(filled_style)
-        .draw(&mut indicator_display)?;
+        let menu_style = self.properties.style();
+        let item_character_style = menu_style.content_text_style(highlighted);
+        let content_position = menu_style.content_position(self.position);
+        if let Some(icon) = &self.properties.icon() {
+            icon.draw_in_item(display, content_position, highlighted, &menu_style)?;
+        }
 
-        let submenu_label_draw_area = display_size.resized_width(
-            display_size.size().width - submenu_indicator_size.width,
-            AnchorX::Left,
-        );
-        let mut label_display = display.cropped(&submenu_label_draw_area);
+        let first_line_height = self.properties.first_line_bounds(self.label).size.height;
+        let label_area = draw_indicator(
+            display,
+            IndicatorDirection::Right,
+            first_line_height,
+            menu_style.content_indicator_color(highlighted),
+        )?;
+        let mut label_display = display.cropped(&label_area);
 
+        let label_position = label_position(&self.properties.icon(), content_position);
         Text::with_baseline(
             self.label,
-            self.position,
+            label_position,
             item_character_style,
             Baseline::Top,
         )
         .draw(&mut label_display)?;
+        draw_description(
+            &mut label_display,
+            self.properties.description(),
+            label_position + Point::new(0, first_line_height as i32),
+            highlighted,
+            &menu_style,
+        )?;
+
+        if let Some(entry) = self.entry.filter(PinEntry::is_editing) {
+            draw_fields(
+                &mut label_display,
+                0,
+                entry.as_str(),
+                entry.active_field(),
+                highlighted,
+                &menu_style,
+            )?;
+        } else if let Some(message) = self.message {
+            draw_fields(
+                &mut label_display,
+                0,
+                message,
+                None,
+                highlighted,
+                &menu_style,
+            )?;
+        }
 
         Ok(())
     }
 }
 
-impl<C, T> MenuItem<T> for SubmenuItem<'_, C, T>
+impl<'a, C, T, X> SubmenuItem<'a, C, T, X>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    /// Set how the items of this submenu are arranged when it is the active menu
+    pub fn set_layout(&mut self, layout: MenuLayout) {
+        self.layout = layout;
+    }
+
+    pub fn layout(&self) -> MenuLayout {
+        self.layout
+    }
+
+    /// Rebuild the items of this submenu every time it is entered, for content that changes such
+    /// as scan results. The callback is handed an empty menu to add the items to, which replace
+    /// the previous ones.
+    pub fn set_populate(&mut self, populate: Option<&'a PopulateFn<'a, C, T, X>>) {
+        self.populate = populate.map(Populate);
+    }
+
+    pub fn populate(&self) -> Option<&'a PopulateFn<'a, C, T, X>> {
+        self.populate.map(|populate| populate.0)
+    }
+
+    /// Ask for a code before the menu switches to this submenu, or open it freely with `None`.
+    /// The code is entered on the row of the item, a digit at a time like a
+    /// [`PinItem`](crate::items::pin::PinItem).
+    pub fn set_lock(&mut self, lock: Option<SubmenuLock<'a>>) {
+        self.lock = lock;
+        self.entry = lock.and_then(|lock| lock.digits()).map(PinEntry::new);
+        self.reset_attempts();
+    }
+
+    pub fn lock(&self) -> Option<SubmenuLock<'a>> {
+        self.lock
+    }
+
+    /// Refuse to open after this many wrong codes in a row until
+    /// [`reset_attempts`](Self::reset_attempts), or keep asking with `None`
+    pub fn set_max_attempts(&mut self, max_attempts: Option<u32>) {
+        self.max_attempts = max_attempts;
+    }
+
+    /// Wrong codes entered since the submenu last opened
+    pub fn failed_attempts(&self) -> u32 {
+        self.failed_attempts
+    }
+
+    /// Whether the submenu ran out of attempts and refuses to open
+    pub fn is_locked_out(&self) -> bool {
+        self.max_attempts
+            .is_some_and(|max_attempts| self.failed_attempts >= max_attempts)
+    }
+
+    /// Forget the wrong codes entered so far, lifting a lockout
+    pub fn reset_attempts(&mut self) {
+        self.failed_attempts = 0;
+        self.message = None;
+    }
+
+    pub fn is_editing(&self) -> bool {
+        self.entry.as_ref().is_some_and(PinEntry::is_editing)
+    }
+
+    /// Change the digit of the code under the cursor or move the cursor
+    pub fn edit(&mut self, action: EditAction) -> Option<SelectedData<T>> {
+        if let Some(entry) = self.entry.as_mut() {
+            entry.edit(action);
+        }
+        None
+    }
+
+    fn denied(&self) -> SelectedData<T> {
+        SelectedData::AccessDenied {
+            id: self.id,
+            locked_out: self.is_locked_out(),
+        }
+    }
+}
+
+impl<C, T, X> MenuItem<T> for SubmenuItem<'_, C, T, X>
 where
     C: PixelColor,
     T: Clone + Copy + Sized,
@@ -111,13 +369,38 @@ where
     }
 }
 
-impl<C, T> MenuItemData<T> for SubmenuItem<'_, C, T>
+impl<C, T, X> MenuItemData<T> for SubmenuItem<'_, C, T, X>
 where
     C: PixelColor,
     T: Clone + Copy + Sized,
 {
+    /// Open the submenu, or for a locked one start entering the code or step to the next digit,
+    /// opening it once the last digit is done if the code is right
     fn selected(&mut self) -> SelectedData<T> {
-        SelectedData::Submenu { id: self.id }
+        let Some(lock) = self.lock else {
+            return SelectedData::Submenu { id: self.id };
+        };
+        if self.is_locked_out() {
+            self.message = Some(LOCKED_OUT_MESSAGE);
+            return self.denied();
+        }
+        self.message = None;
+        let code = match self.entry.as_mut().map(PinEntry::select) {
+            // Mid entry the menu ignores what is reported while editing
+            Some(None) => return self.denied(),
+            code => code.flatten(),
+        };
+        if lock.opens_with(code.as_ref()) {
+            self.failed_attempts = 0;
+            return SelectedData::Submenu { id: self.id };
+        }
+        self.failed_attempts = self.failed_attempts.saturating_add(1);
+        self.message = Some(match (self.is_locked_out(), self.entry) {
+            (true, _) => LOCKED_OUT_MESSAGE,
+            (false, Some(_)) => WRONG_CODE_MESSAGE,
+            (false, None) => DENIED_MESSAGE,
+        });
+        self.denied()
     }
 
     fn display_string(&self) -> &str {
@@ -125,7 +408,7 @@ where
     }
 }
 
-impl<C, T> Debug for SubmenuItem<'_, C, T>
+impl<C, T, X> Debug for SubmenuItem<'_, C, T, X>
 where
     C: PixelColor,
     T: Clone + Copy + Sized,
@@ -135,7 +418,7 @@ where
     }
 }
 
-impl<C, T> Display for SubmenuItem<'_, C, T>
+impl<C, T, X> Display for SubmenuItem<'_, C, T, X>
 where
     C: PixelColor,
     T: Clone + Copy + Sized,
@@ -145,7 +428,7 @@ where
     }
 }
 
-impl<C, T> View for SubmenuItem<'_, C, T>
+impl<C, T, X> View for SubmenuItem<'_, C, T, X>
 where
     C: PixelColor,
     T: Copy + Clone + Sized,
@@ -155,14 +438,11 @@ where
     }
 
     fn bounds(&self) -> Rectangle {
-        self.menu_style
-            .item_character_style
-            .measure_string(self.label, Point::zero(), Baseline::Bottom)
-            .bounding_box
+        self.properties.bounds(self.label)
     }
 }
 
-impl<C, T> Drawable for SubmenuItem<'_, C, T>
+impl<C, T, X> Drawable for SubmenuItem<'_, C, T, X>
 where
     C: PixelColor,
     T: Clone + Copy + Sized,
@@ -174,16 +454,11 @@ where
     where
         D: DrawTarget<Color = Self::Color>,
     {
-        self.draw_item(
-            display,
-            self.menu_style.indicator_fill_color,
-            self.menu_style.item_character_style,
-        )?;
-        Ok(())
+        self.draw_item(display, false)
     }
 }
 
-impl<C: PixelColor, T> DrawableHighlighted for SubmenuItem<'_, C, T>
+impl<C: PixelColor, T, X> DrawableHighlighted for SubmenuItem<'_, C, T, X>
 where
     T: Clone + Copy + Sized,
 {
@@ -194,25 +469,112 @@ where
     where
         D: DrawTarget<Color = Self::Color>,
     {
-        let highlight_box_style = PrimitiveStyleBuilder::new()
-            .fill_color(self.menu_style.highlight_item_color)
-            .build();
-
-        Rectangle::new(
-            self.position,
-            Size::new(
-                display.bounding_box().size().width,
-                self.menu_style.highlight_text_style.line_height(),
-            ),
-        )
-        .into_styled(highlight_box_style)
-        .draw(display)?;
+        self.properties
+            .style()
+            .draw_highlight(display, self.position, self.size().height)?;
+        self.draw_item(display, true)
+    }
+}
 
-        self.draw_item(
-            display,
-            self.menu_style.highlight_indicator_fill_color,
-            self.menu_style.highlight_text_style,
-        )?;
-        Ok(())
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::MenuTheme;
+    use crate::MenuStyleBuilder;
+    use core::sync::atomic::{AtomicBool, Ordering};
+    use embedded_graphics::mono_font::ascii::FONT_6X10;
+    use embedded_graphics::pixelcolor::BinaryColor;
+
+    fn locked_submenu(lock: SubmenuLock<'static>) -> SubmenuItem<'static, BinaryColor, u8> {
+        let menu_style = MenuStyleBuilder::new(&FONT_6X10, &FONT_6X10, MenuTheme::dark()).build();
+        let mut submenu = SubmenuItem::new("Settings", 1, menu_style);
+        submenu.set_lock(Some(lock));
+        submenu
+    }
+
+    /// Enter `code` a digit at a time and select after the last one
+    fn enter(submenu: &mut SubmenuItem<'static, BinaryColor, u8>, code: &[u8]) -> SelectedData<u8> {
+        let mut selected = submenu.selected();
+        for &digit in code {
+            for _ in 0..digit {
+                submenu.edit(EditAction::Increase);
+            }
+            selected = submenu.selected();
+        }
+        selected
+    }
+
+    #[test]
+    fn passcodes_that_cant_be_entered_are_refused() {
+        assert!(SubmenuLock::passcode(&[1, 2, 3, 4]).is_some());
+        assert!(SubmenuLock::passcode(&[9; MAX_PIN_DIGITS]).is_some());
+        assert!(SubmenuLock::passcode(&[]).is_none());
+        assert!(SubmenuLock::passcode(&[1; MAX_PIN_DIGITS + 1]).is_none());
+        assert!(SubmenuLock::passcode(&[1, 10]).is_none());
+    }
+
+    #[test]
+    fn checked_codes_need_a_length_that_can_be_entered() {
+        let check = |_: &PinCode| true;
+        assert!(SubmenuLock::check(1, &check).is_some());
+        assert!(SubmenuLock::check(MAX_PIN_DIGITS, &check).is_some());
+        assert!(SubmenuLock::check(0, &check).is_none());
+        assert!(SubmenuLock::check(MAX_PIN_DIGITS + 1, &check).is_none());
+    }
+
+    #[test]
+    fn right_code_opens() {
+        let mut submenu = locked_submenu(SubmenuLock::passcode(&[1, 2]).unwrap());
+        assert_eq!(
+            enter(&mut submenu, &[1, 2]),
+            SelectedData::Submenu { id: 1 }
+        );
+    }
+
+    #[test]
+    fn wrong_codes_lock_out() {
+        let mut submenu = locked_submenu(SubmenuLock::passcode(&[1, 2]).unwrap());
+        submenu.set_max_attempts(Some(2));
+        let denied = SelectedData::AccessDenied {
+            id: 1,
+            locked_out: false,
+        };
+        assert_eq!(enter(&mut submenu, &[2, 1]), denied);
+        assert_eq!(submenu.failed_attempts(), 1);
+        let locked_out = SelectedData::AccessDenied {
+            id: 1,
+            locked_out: true,
+        };
+        assert_eq!(enter(&mut submenu, &[2, 1]), locked_out);
+        assert!(submenu.is_locked_out());
+        // Even the right code is refused now, without asking for it
+        assert_eq!(submenu.selected(), locked_out);
+        submenu.reset_attempts();
+        assert_eq!(
+            enter(&mut submenu, &[1, 2]),
+            SelectedData::Submenu { id: 1 }
+        );
+    }
+
+    #[test]
+    fn grants_open_without_a_code() {
+        static GRANTED: AtomicBool = AtomicBool::new(false);
+        fn granted() -> bool {
+            GRANTED.load(Ordering::Relaxed)
+        }
+        let mut submenu = locked_submenu(SubmenuLock::grant(&granted));
+        submenu.set_max_attempts(Some(2));
+        assert_eq!(
+            submenu.selected(),
+            SelectedData::AccessDenied {
+                id: 1,
+                locked_out: false
+            }
+        );
+        assert!(!submenu.is_editing());
+        assert_eq!(submenu.failed_attempts(), 1);
+        GRANTED.store(true, Ordering::Relaxed);
+        assert_eq!(submenu.selected(), SelectedData::Submenu { id: 1 });
+        assert_eq!(submenu.failed_attempts(), 0);
     }
 }
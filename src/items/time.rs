@@ -0,0 +1,396 @@
+use crate::icon::label_position;
+use crate::items::field_editor::{draw_fields, wheel_step, FieldCursor};
+use crate::items::properties::ItemProperties;
+use crate::items::{
+    text_width, DrawableHighlighted, EditAction, MenuItem, MenuItemData, SelectedData,
+};
+use crate::text_buffer::TextBuffer;
+use crate::MenuStyle;
+use core::fmt;
+use core::fmt::{Debug, Display, Formatter};
+use embedded_graphics::draw_target::DrawTarget;
+use embedded_graphics::geometry::Point;
+use embedded_graphics::pixelcolor::PixelColor;
+use embedded_graphics::primitives::Rectangle;
+use embedded_graphics::text::{Baseline, Text};
+use embedded_graphics::Drawable;
+use embedded_layout::View;
+
+/// Time of day on a 24 hour clock, always in range
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Time {
+    hour: u8,
+    minute: u8,
+    second: u8,
+}
+
+impl Time {
+    /// The time, or `None` if any part is out of range
+    pub const fn new(hour: u8, minute: u8, second: u8) -> Option<Self> {
+        if hour < 24 && minute < 60 && second < 60 {
+            Some(Self {
+                hour,
+                minute,
+                second,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Hour of the day, 0 to 23
+    pub const fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    pub const fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    pub const fn second(&self) -> u8 {
+        self.second
+    }
+}
+
+/// Hours, minutes and seconds
+const FIELD_COUNT: usize = 3;
+
+/// Longest value text, "12:59:59 PM"
+const VALUE_TEXT_LEN: usize = 11;
+
+/// Editor for a time of day, shown as "HH:MM:SS" or with a 12 hour clock as "HH:MM:SS AM".
+/// Selecting the item starts editing the hours; up and down change the field, left and right
+/// move between fields, and selecting steps on to the next field, finishing after the seconds.
+#[derive(PartialEq, Clone, Copy)]
+pub struct TimeItem<'a, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    label: &'static str,
+    position: Point,
+    pub(crate) properties: ItemProperties<'a, C>,
+    time: Time,
+    twelve_hour: bool,
+    value_text: TextBuffer<VALUE_TEXT_LEN>,
+    cursor: FieldCursor,
+    id: T,
+}
+
+impl<C, T> TimeItem<'_, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    pub fn new<'a>(
+        label: &'static str,
+        id: T,
+        menu_style: MenuStyle<'a, C>,
+        time: Time,
+    ) -> TimeItem<'a, C, T> {
+        let mut item = TimeItem {
+            label,
+            position: Point::zero(),
+            properties: ItemProperties::new(menu_style),
+            time,
+            twelve_hour: false,
+            value_text: TextBuffer::new(),
+            cursor: FieldCursor::new(),
+            id,
+        };
+        item.update_value_text();
+        item
+    }
+
+    fn draw_item<D>(&self, display: &mut D, highlighted: bool) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let menu_style = self.properties.style();
+        let item_character_style = menu_style.content_text_style(highlighted);
+        let content_position = menu_style.content_position(self.position);
+        if let Some(icon) = &self.properties.icon() {
+            icon.draw_in_item(display, content_position, highlighted, &menu_style)?;
+        }
+
+        let label_position = label_position(&self.properties.icon(), content_position);
+        Text::with_baseline(
+            self.label,
+            label_position,
+            item_character_style,
+            Baseline::Top,
+        )
+        .draw(display)?;
+        self.properties
+            .draw_description(display, self.label, label_position, highlighted)?;
+
+        // Each field is two digits followed by a separator
+        let active_field = self.cursor.field().map(|field| field * 3..field * 3 + 2);
+        let value_top = match self.value_wraps(display.bounding_box().size().width) {
+            true => self.size().height as i32,
+            false => 0,
+        };
+        draw_fields(
+            display,
+            value_top,
+            self.display_string(),
+            active_field,
+            highlighted,
+            &menu_style,
+        )
+    }
+}
+
+impl<'a, C, T> TimeItem<'a, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    pub fn set_time(&mut self, time: Time) {
+        self.time = time;
+        self.update_value_text();
+    }
+
+    pub fn time(&self) -> Time {
+        self.time
+    }
+
+    /// Show the time on a 12 hour clock with AM and PM. The time reported stays on a 24 hour
+    /// clock.
+    pub fn set_twelve_hour(&mut self, twelve_hour: bool) {
+        self.twelve_hour = twelve_hour;
+        self.update_value_text();
+    }
+
+    pub fn is_editing(&self) -> bool {
+        self.cursor.is_editing()
+    }
+
+    /// Change the field under the cursor or move the cursor. Times are only reported once
+    /// editing finishes.
+    pub fn edit(&mut self, action: EditAction) -> Option<SelectedData<T>> {
+        let time = &mut self.time;
+        match self.cursor.apply(action, FIELD_COUNT) {
+            Some(0) => time.hour = wheel_step(time.hour as u32, 0, 23, action) as u8,
+            Some(1) => time.minute = wheel_step(time.minute as u32, 0, 59, action) as u8,
+            Some(2) => time.second = wheel_step(time.second as u32, 0, 59, action) as u8,
+            _ => {}
+        }
+        self.update_value_text();
+        None
+    }
+
+    fn update_value_text(&mut self) {
+        let Time {
+            hour,
+            minute,
+            second,
+        } = self.time;
+        self.value_text = match self.twelve_hour {
+            true => {
+                let suffix = if hour < 12 { "AM" } else { "PM" };
+                let hour = match hour % 12 {
+                    0 => 12,
+                    hour => hour,
+                };
+                TextBuffer::from_fmt(format_args!(
+                    "{:02}:{:02}:{:02} {}",
+                    hour, minute, second, suffix
+                ))
+            }
+            false => TextBuffer::from_fmt(format_args!("{:02}:{:02}:{:02}", hour, minute, second)),
+        };
+    }
+
+    /// Height of the item when drawn `width` wide, with a second line for the value if it doesn't
+    /// fit beside the label
+    pub fn height_in(&self, width: u32) -> u32 {
+        self.properties.height(self.label, self.value_wraps(width))
+    }
+
+    fn value_wraps(&self, width: u32) -> bool {
+        self.properties.value_wraps(
+            self.label,
+            self.position,
+            text_width(
+                &self.properties.style().value_character_style,
+                self.display_string(),
+            ),
+            width,
+        )
+    }
+}
+
+impl<C, T> MenuItem<T> for TimeItem<'_, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    fn label(&self) -> &'static str {
+        self.label
+    }
+    fn id(&self) -> T {
+        self.id
+    }
+}
+
+impl<C: PixelColor, T> Debug for TimeItem<'_, C, T>
+where
+    T: Clone + Copy + Sized,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "[\"{}\":Time]", self.label)
+    }
+}
+
+impl<C: PixelColor, T> Display for TimeItem<'_, C, T>
+where
+    T: Clone + Copy + Sized,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.label)
+    }
+}
+
+impl<C: PixelColor, T> View for TimeItem<'_, C, T>
+where
+    T: Clone + Copy + Sized,
+{
+    fn translate_impl(&mut self, by: Point) {
+        self.position += by;
+    }
+
+    fn bounds(&self) -> Rectangle {
+        self.properties.bounds(self.label)
+    }
+}
+
+impl<C: PixelColor, T> Drawable for TimeItem<'_, C, T>
+where
+    T: Clone + Copy + Sized,
+{
+    type Color = C;
+    type Output = ();
+
+    fn draw<D>(&self, display: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        self.draw_item(display, false)
+    }
+}
+
+impl<C: PixelColor, T> DrawableHighlighted for TimeItem<'_, C, T>
+where
+    T: Clone + Copy + Sized,
+{
+    type Color = C;
+    type Output = ();
+
+    fn draw_highlighted<D>(&self, display: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        self.properties.style().draw_highlight(
+            display,
+            self.position,
+            self.height_in(display.bounding_box().size().width),
+        )?;
+        self.draw_item(display, true)
+    }
+}
+
+impl<C, T> MenuItemData<T> for TimeItem<'_, C, T>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+{
+    /// Start editing or step to the next field, reporting the time once the last is done
+    fn selected(&mut self) -> SelectedData<T> {
+        self.cursor.select(FIELD_COUNT);
+        SelectedData::Time {
+            id: self.id,
+            time: self.time,
+        }
+    }
+
+    fn display_string(&self) -> &str {
+        self.value_text.as_str()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::MenuTheme;
+    use crate::MenuStyleBuilder;
+    use embedded_graphics::mono_font::ascii::FONT_6X10;
+    use embedded_graphics::pixelcolor::BinaryColor;
+
+    fn time_item(time: Time) -> TimeItem<'static, BinaryColor, u8> {
+        let menu_style = MenuStyleBuilder::new(&FONT_6X10, &FONT_6X10, MenuTheme::dark()).build();
+        TimeItem::new("Time", 1, menu_style, time)
+    }
+
+    #[test]
+    fn only_real_times_are_made() {
+        assert!(Time::new(23, 59, 59).is_some());
+        assert!(Time::new(24, 0, 0).is_none());
+        assert!(Time::new(0, 60, 0).is_none());
+        assert!(Time::new(0, 0, 60).is_none());
+    }
+
+    #[test]
+    fn fields_are_edited_one_at_a_time() {
+        let mut item = time_item(Time::new(7, 30, 0).unwrap());
+        item.selected();
+        assert!(item.is_editing());
+        item.edit(EditAction::Increase);
+        item.edit(EditAction::Next);
+        item.edit(EditAction::Decrease);
+        item.selected();
+        item.edit(EditAction::Increase);
+        assert_eq!(item.time(), Time::new(8, 29, 1).unwrap());
+        assert_eq!(item.display_string(), "08:29:01");
+
+        // Selecting on the seconds finishes and reports the time
+        assert_eq!(
+            item.selected(),
+            SelectedData::Time {
+                id: 1,
+                time: Time::new(8, 29, 1).unwrap()
+            }
+        );
+        assert!(!item.is_editing());
+    }
+
+    #[test]
+    fn fields_wrap_around() {
+        let mut item = time_item(Time::new(23, 59, 59).unwrap());
+        item.selected();
+        item.edit(EditAction::Increase);
+        item.edit(EditAction::Next);
+        item.edit(EditAction::Increase);
+        item.edit(EditAction::Next);
+        item.edit(EditAction::Increase);
+        assert_eq!(item.time(), Time::new(0, 0, 0).unwrap());
+
+        // Minutes and seconds wrap on their own without carrying into the field before
+        item.edit(EditAction::Decrease);
+        item.edit(EditAction::Previous);
+        item.edit(EditAction::Decrease);
+        assert_eq!(item.time(), Time::new(0, 59, 59).unwrap());
+    }
+
+    #[test]
+    fn set_time_updates_the_shown_time() {
+        let mut item = time_item(Time::new(7, 30, 0).unwrap());
+        item.set_time(Time::new(0, 5, 9).unwrap());
+        assert_eq!(item.display_string(), "00:05:09");
+        item.set_twelve_hour(true);
+        assert_eq!(item.display_string(), "12:05:09 AM");
+        item.set_time(Time::new(13, 0, 0).unwrap());
+        assert_eq!(item.display_string(), "01:00:00 PM");
+        assert_eq!(item.time(), Time::new(13, 0, 0).unwrap());
+    }
+}
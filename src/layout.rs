@@ -0,0 +1,203 @@
+use crate::highlight::HighlightRenderer;
+use crate::items::custom::CustomMenuItem;
+use crate::items::{MenuItem, MenuItems};
+use crate::MenuStyle;
+use embedded_graphics::draw_target::{DrawTarget, DrawTargetExt};
+use embedded_graphics::geometry::{Point, Size};
+use embedded_graphics::pixelcolor::PixelColor;
+use embedded_graphics::primitives::Rectangle;
+use embedded_graphics::text::renderer::TextRenderer;
+use embedded_graphics::text::{Alignment, Baseline, Text, TextStyleBuilder};
+use embedded_graphics::Drawable;
+use trees::Tree;
+
+/// Arrangement of the items of one menu level on the display
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MenuLayout {
+    /// One item per row, scrolling vertically. Left/right navigation does nothing.
+    List,
+    /// Pages of `columns` x `rows` tiles showing each item's icon above its label. Up/down move
+    /// between rows and left/right move between neighbouring tiles.
+    Grid { columns: usize, rows: usize },
+    /// The highlighted item's icon is shown in the middle with its neighbours either side and its
+    /// label beneath. Every direction moves to the previous or next item.
+    Carousel,
+}
+
+pub(crate) fn draw_grid<'a, D, C, T, X>(
+    display: &mut D,
+    menu_tree: &Tree<MenuItems<'a, C, T, X>>,
+    highlighted_item: usize,
+    columns: usize,
+    rows: usize,
+    menu_style: &MenuStyle<'_, C>,
+) -> Result<(), D::Error>
+where
+    D: DrawTarget<Color = C>,
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+    X: CustomMenuItem<'a, C, T>,
+{
+    let columns = columns.max(1);
+    let rows = rows.max(1);
+    let display_size = display.bounding_box().size;
+    let tile_size = Size::new(
+        display_size.width / columns as u32,
+        display_size.height / rows as u32,
+    );
+
+    let items_per_page = columns * rows;
+    let first_item = (highlighted_item / items_per_page) * items_per_page;
+
+    let page_iter = menu_tree
+        .iter()
+        .enumerate()
+        .skip(first_item)
+        .take(items_per_page);
+    for (index, menu_item) in page_iter {
+        let tile_index = index - first_item;
+        let tile_top_left = Point::new(
+            (tile_index % columns) as i32 * tile_size.width as i32,
+            (tile_index / columns) as i32 * tile_size.height as i32,
+        );
+        let mut tile_display = display.cropped(&Rectangle::new(tile_top_left, tile_size));
+        draw_tile(
+            &mut tile_display,
+            menu_item.data(),
+            index == highlighted_item,
+            true,
+            menu_style,
+        )?;
+    }
+
+    Ok(())
+}
+
+pub(crate) fn draw_carousel<'a, D, C, T, X>(
+    display: &mut D,
+    menu_tree: &Tree<MenuItems<'a, C, T, X>>,
+    highlighted_item: usize,
+    menu_style: &MenuStyle<'_, C>,
+) -> Result<(), D::Error>
+where
+    D: DrawTarget<Color = C>,
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+    X: CustomMenuItem<'a, C, T>,
+{
+    let display_size = display.bounding_box().size;
+    let label_height = menu_style.item_character_style.line_height();
+    let tile_size = Size::new(
+        display_size.width / 3,
+        display_size.height.saturating_sub(label_height),
+    );
+
+    // Previous, highlighted and next item from left to right
+    for (slot, index) in [
+        highlighted_item.checked_sub(1),
+        Some(highlighted_item),
+        highlighted_item.checked_add(1),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let Some(menu_item) = index.and_then(|index| menu_tree.iter().nth(index)) else {
+            continue;
+        };
+        let tile_top_left = Point::new(slot as i32 * tile_size.width as i32, 0);
+        let mut tile_display = display.cropped(&Rectangle::new(tile_top_left, tile_size));
+        draw_tile(
+            &mut tile_display,
+            menu_item.data(),
+            slot == 1,
+            false,
+            menu_style,
+        )?;
+    }
+
+    if let Some(menu_item) = menu_tree.iter().nth(highlighted_item) {
+        Text::with_text_style(
+            menu_item.data().label(),
+            Point::new(display_size.width as i32 / 2, tile_size.height as i32),
+            menu_style.item_character_style,
+            TextStyleBuilder::new()
+                .alignment(Alignment::Center)
+                .baseline(Baseline::Top)
+                .build(),
+        )
+        .draw(display)?;
+    }
+
+    Ok(())
+}
+
+/// Draw an item as its icon centred in the tile, with the label beneath if `show_label` is set.
+/// Items without an icon always show their label in its place.
+fn draw_tile<'a, D, C, T, X>(
+    display: &mut D,
+    item: &MenuItems<'a, C, T, X>,
+    highlighted: bool,
+    show_label: bool,
+    menu_style: &MenuStyle<'_, C>,
+) -> Result<(), D::Error>
+where
+    D: DrawTarget<Color = C>,
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+    X: CustomMenuItem<'a, C, T>,
+{
+    let menu_style = &menu_style.with_override(item.style_override());
+    let tile_size = display.bounding_box().size;
+    let text_style = menu_style.content_text_style(highlighted);
+    let centered_text = TextStyleBuilder::new()
+        .alignment(Alignment::Center)
+        .baseline(Baseline::Top)
+        .build();
+
+    if highlighted {
+        menu_style
+            .highlight_style
+            .draw_highlight(display, display.bounding_box(), menu_style)?;
+    }
+
+    match item.icon() {
+        Some(icon) => {
+            let label_height = match show_label {
+                true => text_style.line_height(),
+                false => 0,
+            };
+            let icon_size = icon.size();
+            let icon_position = Point::new(
+                (tile_size.width as i32 - icon_size.width as i32) / 2,
+                (tile_size.height as i32 - (icon_size.height + label_height) as i32) / 2,
+            );
+            icon.draw_in_item(display, icon_position, highlighted, menu_style)?;
+            if show_label {
+                Text::with_text_style(
+                    item.label(),
+                    Point::new(
+                        tile_size.width as i32 / 2,
+                        icon_position.y + icon_size.height as i32,
+                    ),
+                    text_style,
+                    centered_text,
+                )
+                .draw(display)?;
+            }
+        }
+        None => {
+            Text::with_text_style(
+                item.label(),
+                Point::new(
+                    tile_size.width as i32 / 2,
+                    (tile_size.height as i32 - text_style.line_height() as i32) / 2,
+                ),
+                text_style,
+                centered_text,
+            )
+            .draw(display)?;
+        }
+    }
+
+    Ok(())
+}
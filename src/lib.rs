@@ -1,35 +1,76 @@
 #![no_std]
+use core::cell::Cell;
 use core::cmp::PartialEq;
 use core::pin::Pin;
-use items::{DrawableHighlighted, MenuItem, MenuItemData, SelectedData};
+use items::{DrawableHighlighted, EditAction, MenuItem, MenuItemData, SelectedData};
 
+pub mod footer;
+pub mod header;
+mod help;
+pub mod highlight;
+pub mod icon;
 pub mod items;
+pub mod layout;
+pub mod redraw;
+pub mod rotation;
+mod text_buffer;
+pub mod theme;
 
 use embedded_graphics::geometry::AnchorY;
-use embedded_graphics::mono_font::MonoTextStyle;
+use embedded_graphics::mono_font::{MonoFont, MonoTextStyle};
 use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::{Line, PrimitiveStyle, Rectangle};
 use embedded_graphics::text::renderer::TextRenderer;
-use embedded_graphics::text::{Baseline, Text};
 use embedded_layout::View;
+use footer::SoftKeyHints;
+use header::{HeaderStyle, MAX_STATUS_ICONS};
+use help::HelpPage;
+use highlight::{HighlightRenderer, HighlightStyle};
+use icon::MenuIcon;
 use items::action::ActionItem;
 use items::back_item::BackItem;
-use items::checkbox::CheckboxItem;
+use items::checkbox::{CheckboxItem, CheckboxStyle};
+use items::custom::{CustomItem, CustomMenuItem, NoCustomItems};
+use items::date::{Date, DateItem};
 use items::exit_item::ExitItem;
+use items::hex::HexItem;
+use items::ipv4::Ipv4Item;
+use items::list::{ListItem, ListSource};
 use items::multi_option::MultiOptionItem;
+use items::pin::PinItem;
+use items::progress::{Progress, ProgressItem};
+use items::radio::RadioItem;
 use items::section::SectionItem;
-use items::submenu::SubmenuItem;
+use items::slider::SliderItem;
+use items::submenu::{PopulateFn, SubmenuItem, SubmenuLock};
+use items::time::{Time, TimeItem};
 use items::MenuItems;
-use trees::Tree;
+use layout::MenuLayout;
+use redraw::{DirtyRegions, DrawReport, RenderMode};
+use rotation::{RotatedTarget, Rotation};
+use theme::MenuTheme;
+use trees::{Forest, Node, Tree};
 
-pub struct Menu<'a, C, T>
+pub struct Menu<'a, C, T, X = NoCustomItems<C>>
 where
     C: PixelColor,
     T: Clone + Copy + Sized,
 {
-    menu_tree_root: Tree<MenuItems<'a, C, T>>,
+    menu_tree_root: Tree<MenuItems<'a, C, T, X>>,
     menu_style: MenuStyle<'a, C>,
     menu_state: MenuState,
-    active_submenu_node: Option<Tree<MenuItems<'a, C, T>>>,
+    active_submenu_node: Option<Tree<MenuItems<'a, C, T, X>>>,
+    navigation_path: NavigationPath,
+    status_icons: [Option<MenuIcon<'a, C>>; MAX_STATUS_ICONS],
+    dirty_regions: Cell<DirtyRegions>,
+    viewport: Option<Rectangle>,
+    clear_background: bool,
+    render_mode: RenderMode,
+    rotation: Rotation,
+    /// Draws since the last full refresh hint in e-paper mode
+    partial_updates: Cell<u32>,
+    help_page: Option<HelpPage>,
+    edit_change: Option<SelectedData<T>>,
 }
 
 impl<'a, C, T> Menu<'a, C, T>
@@ -38,6 +79,23 @@ where
     T: Clone + Copy + Sized,
 {
     pub fn new(label: &'static str, root_id: T, menu_style: MenuStyle<'a, C>) -> Self {
+        Self::with_custom_items(label, root_id, menu_style)
+    }
+}
+
+impl<'a, C, T, X> Menu<'a, C, T, X>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized,
+    X: CustomMenuItem<'a, C, T>,
+{
+    /// Menu that can also hold items of the application's own kind `X`, see [`CustomMenuItem`].
+    /// Its submenus are made the same way.
+    pub fn with_custom_items(
+        label: &'static str,
+        root_id: T,
+        menu_style: MenuStyle<'a, C>,
+    ) -> Self {
         let tree_root = Tree::new(MenuItems::Submenu(SubmenuItem::new(
             label, root_id, menu_style,
         )));
@@ -46,14 +104,25 @@ where
             menu_style,
             menu_state: MenuState::new(),
             active_submenu_node: None,
+            navigation_path: NavigationPath::new(),
+            status_icons: [None; MAX_STATUS_ICONS],
+            dirty_regions: Cell::new(DirtyRegions::new()),
+            viewport: None,
+            clear_background: true,
+            render_mode: RenderMode::Standard,
+            rotation: Rotation::Deg0,
+            partial_updates: Cell::new(0),
+            help_page: None,
+            edit_change: None,
         }
     }
 
     /// Add menu item to the menu structure that will be drawn
-    pub fn add_item(&mut self, item: MenuItems<'a, C, T>) {
+    pub fn add_item(&mut self, item: MenuItems<'a, C, T, X>) {
         self.menu_tree_root.push_back(Tree::new(item));
         self.menu_state
             .update_item_count(self.menu_tree_root.iter().count());
+        self.mark_dirty(DirtyRegions::mark_everything);
     }
 
     /// Add checkbox as next item in the menu
@@ -83,6 +152,97 @@ where
         )));
     }
 
+    /// Add a radio group as the next items in the menu, one row per option sharing the `id`.
+    /// Selecting a row chooses that option and gives [`SelectedData::MultiOption`] like a
+    /// selector does. Setting an icon, description or help text by `id` sets it on every row.
+    pub fn add_radio_group(&mut self, id: T, options: &'a [&'static str], chosen_option: usize) {
+        for option_index in 0..options.len() {
+            self.add_item(MenuItems::Radio(RadioItem::new(
+                id,
+                self.menu_style,
+                options,
+                option_index,
+                option_index == chosen_option,
+            )));
+        }
+    }
+
+    /// Add a slider between `min` and `max` inclusive as next item in the menu. Use
+    /// [`add_item`](Menu::add_item) with a [`SliderItem`] for a different step or to report every
+    /// change.
+    pub fn add_slider(&mut self, label: &'static str, id: T, min: i32, max: i32, value: i32) {
+        self.add_item(MenuItems::Slider(SliderItem::new(
+            label,
+            id,
+            self.menu_style,
+            min,
+            max,
+            value,
+        )));
+    }
+
+    /// Add a time of day editor as next item in the menu, on a 24 hour clock
+    pub fn add_time(&mut self, label: &'static str, id: T, time: Time) {
+        self.add_item(MenuItems::Time(TimeItem::new(
+            label,
+            id,
+            self.menu_style,
+            time,
+        )));
+    }
+
+    /// Add a calendar date editor as next item in the menu
+    pub fn add_date(&mut self, label: &'static str, id: T, date: Date) {
+        self.add_item(MenuItems::Date(DateItem::new(
+            label,
+            id,
+            self.menu_style,
+            date,
+        )));
+    }
+
+    /// Add a dotted IPv4 address editor as next item in the menu
+    pub fn add_ipv4(&mut self, label: &'static str, id: T, address: core::net::Ipv4Addr) {
+        self.add_item(MenuItems::Ipv4(Ipv4Item::new(
+            label,
+            id,
+            self.menu_style,
+            address,
+        )));
+    }
+
+    /// Add an editor for a value of `digits` hex digits as next item in the menu
+    pub fn add_hex(&mut self, label: &'static str, id: T, digits: usize, value: u32) {
+        self.add_item(MenuItems::Hex(HexItem::new(
+            label,
+            id,
+            self.menu_style,
+            digits,
+            value,
+        )));
+    }
+
+    /// Add an entry for a code of `digits` digits as next item in the menu
+    pub fn add_pin(&mut self, label: &'static str, id: T, digits: usize) {
+        self.add_item(MenuItems::Pin(PinItem::new(
+            label,
+            id,
+            self.menu_style,
+            digits,
+        )));
+    }
+
+    /// Add a progress bar as next item in the menu, for the application to update with
+    /// [`set_progress`](Menu::set_progress)
+    pub fn add_progress(&mut self, label: &'static str, id: T, progress: Progress) {
+        self.add_item(MenuItems::Progress(ProgressItem::new(
+            label,
+            id,
+            self.menu_style,
+            progress,
+        )));
+    }
+
     /// Add section (non-selectable item) as next item in the menu
     pub fn add_section(&mut self, label: &'static str, id: T) {
         self.add_item(MenuItems::Section(SectionItem::new(
@@ -93,10 +253,27 @@ where
     }
 
     /// Add a sub-menu to the menu structure that will be drawn
-    pub fn add_submenu(&mut self, submenu: Menu<'a, C, T>) {
+    pub fn add_submenu(&mut self, submenu: Menu<'a, C, T, X>) {
         self.menu_tree_root.push_back(submenu.into());
         self.menu_state
             .update_item_count(self.menu_tree_root.iter().count());
+        self.mark_dirty(DirtyRegions::mark_everything);
+    }
+
+    /// Add a row opening a list of entries read from `source`, for lists too long to add as items.
+    /// Selecting an entry reports [`SelectedData::ListEntry`] with its index.
+    pub fn add_list(&mut self, label: &'static str, id: T, source: &'a dyn ListSource) {
+        self.add_item(MenuItems::List(ListItem::new(
+            label,
+            id,
+            self.menu_style,
+            source,
+        )));
+    }
+
+    /// Add an item of the application's own kind, see [`CustomMenuItem`]
+    pub fn add_custom(&mut self, item: X) {
+        self.add_item(MenuItems::Custom(CustomItem::new(self.menu_style, item)));
     }
 
     /// Add back item to the menu
@@ -117,34 +294,217 @@ where
         self.add_item(MenuItems::Exit(ExitItem::new(label, id, self.menu_style)));
     }
 
+    /// Change the style of every item in the menu. Items keep their own style overrides.
+    pub fn set_style(&mut self, menu_style: MenuStyle<'a, C>) {
+        self.menu_style = menu_style;
+        self.mark_dirty(DirtyRegions::mark_everything);
+        let (root, active_root) = self.tree_roots_mut();
+        Self::update_all_items(root, &|item| item.set_menu_style(menu_style));
+        if let Some(active_root) = active_root {
+            Self::update_all_items(active_root, &|item| item.set_menu_style(menu_style));
+        }
+    }
+
+    fn update_all_items<F>(node: &mut Node<MenuItems<'a, C, T, X>>, update: &F)
+    where
+        F: Fn(&mut MenuItems<'a, C, T, X>),
+    {
+        update(node.data_mut());
+        for child in node.iter_mut() {
+            Self::update_all_items(unpin(child), update);
+        }
+    }
+
+    /// Set the status icon shown in `slot` on the right of the header, counting from the right
+    /// edge. Slots past [`MAX_STATUS_ICONS`] are ignored.
+    pub fn set_status_icon(&mut self, slot: usize, icon: Option<MenuIcon<'a, C>>) {
+        if let Some(status_icon) = self.status_icons.get_mut(slot) {
+            *status_icon = icon;
+            self.mark_dirty(DirtyRegions::mark_header);
+        }
+    }
+
+    /// Draw the menu into `viewport`, in the coordinates of the draw target, instead of the whole
+    /// target. Nothing is drawn outside the viewport.
+    pub fn set_viewport(&mut self, viewport: Option<Rectangle>) {
+        self.viewport = viewport;
+        self.mark_dirty(DirtyRegions::mark_everything);
+    }
+
+    /// Fill the viewport with the menu background colour before drawing. Turn this off to draw the
+    /// menu over other content; the application then has to restore that content before every
+    /// draw, and [`Menu::draw_changes`] redraws the whole menu whenever anything changed.
+    pub fn set_background_clearing(&mut self, clear_background: bool) {
+        self.clear_background = clear_background;
+        self.mark_dirty(DirtyRegions::mark_everything);
+    }
+
+    /// Rotate the menu clockwise within its viewport. At 90 and 270 degrees a landscape display
+    /// gives a narrow portrait menu, where values that don't fit beside their label move to a
+    /// second line.
+    pub fn set_rotation(&mut self, rotation: Rotation) {
+        self.rotation = rotation;
+        self.mark_dirty(DirtyRegions::mark_everything);
+    }
+
+    /// Choose between redrawing immediately and the e-paper friendly mode, see [`RenderMode`]
+    pub fn set_render_mode(&mut self, render_mode: RenderMode) {
+        self.render_mode = render_mode;
+        self.partial_updates.set(0);
+        self.mark_dirty(DirtyRegions::mark_everything);
+    }
+
+    /// Rebuild the items of this menu every time it is entered as a submenu, for content that
+    /// changes such as scan results. The callback is handed an empty menu to add the items to,
+    /// which replace the previous ones before the menu is shown. Edits made to the previous items,
+    /// such as a toggled checkbox, are discarded with them.
+    pub fn set_populate(&mut self, populate: Option<&'a PopulateFn<'a, C, T, X>>) {
+        let root = unpin(self.menu_tree_root.root_mut());
+        if let MenuItems::Submenu(item) = root.data_mut() {
+            item.set_populate(populate);
+        }
+    }
+
+    /// Set how the items of this menu are arranged when it is the active menu
+    pub fn set_layout(&mut self, layout: MenuLayout) {
+        // The root of a menu is always a submenu item
+        let root = unpin(self.menu_tree_root.root_mut());
+        if let MenuItems::Submenu(item) = root.data_mut() {
+            item.set_layout(layout);
+        }
+        self.mark_dirty(DirtyRegions::mark_everything);
+    }
+
+    fn get_active_layout(&self) -> MenuLayout {
+        match self.get_active_submenu().data() {
+            MenuItems::Submenu(item) => item.layout(),
+            _ => MenuLayout::List,
+        }
+    }
+
     pub fn navigate_down(&mut self) {
-        self.menu_state.move_down();
-        if let Some(item) = self
-            .get_active_submenu()
-            .iter()
-            .nth(self.menu_state.highlighted_item())
-        {
-            if let MenuItems::Section(_) = item.data() {
-                self.menu_state.move_down();
+        if let Some(help_page) = self.help_page.as_mut() {
+            help_page.scroll_down();
+            self.mark_dirty(DirtyRegions::mark_everything);
+            return;
+        }
+        if self.edit_highlighted(EditAction::Decrease) {
+            return;
+        }
+        match self.get_active_layout() {
+            MenuLayout::Grid { columns, .. } => {
+                self.navigate_with(|menu_state| menu_state.move_down_by(columns))
             }
+            _ => self.navigate_with(MenuState::move_down),
         }
     }
 
     pub fn navigate_up(&mut self) {
-        self.menu_state.move_up();
-        if let Some(item) = self
-            .menu_tree_root
-            .iter()
-            .nth(self.menu_state.highlighted_item())
-        {
-            if let MenuItems::Section(_) = item.data() {
-                self.menu_state.move_up();
+        if let Some(help_page) = self.help_page.as_mut() {
+            help_page.scroll_up();
+            self.mark_dirty(DirtyRegions::mark_everything);
+            return;
+        }
+        if self.edit_highlighted(EditAction::Increase) {
+            return;
+        }
+        match self.get_active_layout() {
+            MenuLayout::Grid { columns, .. } => {
+                self.navigate_with(|menu_state| menu_state.move_up_by(columns))
+            }
+            _ => self.navigate_with(MenuState::move_up),
+        }
+    }
+
+    pub fn navigate_left(&mut self) {
+        // The help page covers the menu, so nothing underneath it changes
+        if self.help_page.is_some() {
+            return;
+        }
+        if self.edit_highlighted(EditAction::Previous) {
+            return;
+        }
+        match self.get_active_layout() {
+            MenuLayout::List => {}
+            _ => self.navigate_with(MenuState::move_up),
+        }
+    }
+
+    pub fn navigate_right(&mut self) {
+        // The help page covers the menu, so nothing underneath it changes
+        if self.help_page.is_some() {
+            return;
+        }
+        if self.edit_highlighted(EditAction::Next) {
+            return;
+        }
+        match self.get_active_layout() {
+            MenuLayout::List => {}
+            _ => self.navigate_with(MenuState::move_down),
+        }
+    }
+
+    /// Pass a navigation key to the highlighted item if it is being edited. Returns whether it
+    /// was, in which case the highlight stays where it is.
+    fn edit_highlighted(&mut self, action: EditAction) -> bool {
+        let highlighted_item = self.menu_state.highlighted_item();
+        let active_tree = self.get_mut_active_submenu();
+        let Some(item) = active_tree.iter_mut().nth(highlighted_item) else {
+            return false;
+        };
+        let item = unpin(item);
+        if !item.data().is_editing() {
+            return false;
+        }
+        if let Some(change) = item.data_mut().edit(action) {
+            self.edit_change = Some(change);
+        }
+        self.store_active_item(highlighted_item);
+        self.mark_dirty(|dirty_regions| dirty_regions.mark_item(highlighted_item));
+        true
+    }
+
+    /// Move the highlight with `step`, stepping again while it lands on items that can't be
+    /// highlighted
+    fn navigate_with<F>(&mut self, step: F)
+    where
+        F: Fn(&mut MenuState),
+    {
+        let previous_item = self.effective_highlighted_item();
+        let previous_hints = self.soft_key_hints(previous_item);
+        step(&mut self.menu_state);
+        // Keep stepping past items that can't be highlighted, at most once round the menu
+        let item_count = self.get_active_submenu().iter().count();
+        for _ in 0..item_count {
+            match self
+                .get_active_submenu()
+                .iter()
+                .nth(self.menu_state.highlighted_item())
+            {
+                Some(item) if !item.data().is_highlightable() => step(&mut self.menu_state),
+                _ => break,
             }
         }
+
+        let highlighted_item = self.effective_highlighted_item();
+        if highlighted_item != previous_item {
+            let footer_changed = self.soft_key_hints(highlighted_item) != previous_hints;
+            let help_panel_shown = self.menu_style.help_panel_lines > 0;
+            self.mark_dirty(|dirty_regions| {
+                dirty_regions.mark_item(previous_item);
+                dirty_regions.mark_item(highlighted_item);
+                if footer_changed {
+                    dirty_regions.mark_footer();
+                }
+                if help_panel_shown {
+                    dirty_regions.mark_help_panel();
+                }
+            });
+        }
     }
 
-    fn get_mut_active_submenu(&mut self) -> &mut Tree<MenuItems<'a, C, T>> {
-        let menu_tree: &mut Tree<MenuItems<'_, C, T>>;
+    fn get_mut_active_submenu(&mut self) -> &mut Tree<MenuItems<'a, C, T, X>> {
+        let menu_tree: &mut Tree<MenuItems<'a, C, T, X>>;
         if let Some(active_tree) = self.active_submenu_node.as_mut() {
             menu_tree = active_tree;
         } else {
@@ -153,8 +513,19 @@ where
         menu_tree
     }
 
-    fn get_active_submenu(&self) -> &Tree<MenuItems<'a, C, T>> {
-        let menu_tree: &Tree<MenuItems<'_, C, T>>;
+    /// Roots of the menu structure and of the copy of the active submenu, if one is shown, for
+    /// changes that have to be made to both
+    fn tree_roots_mut(&mut self) -> TreeRoots<'_, 'a, C, T, X> {
+        (
+            unpin(self.menu_tree_root.root_mut()),
+            self.active_submenu_node
+                .as_mut()
+                .map(|active_tree| unpin(active_tree.root_mut())),
+        )
+    }
+
+    fn get_active_submenu(&self) -> &Tree<MenuItems<'a, C, T, X>> {
+        let menu_tree: &Tree<MenuItems<'a, C, T, X>>;
         if let Some(active_tree) = &self.active_submenu_node {
             menu_tree = active_tree;
         } else {
@@ -163,56 +534,172 @@ where
         menu_tree
     }
 
-    fn navigate_to_menu(&mut self, target: Tree<MenuItems<'a, C, T>>) {
+    /// The list shown in place of a menu after opening a [`ListItem`]
+    fn active_list(&self) -> Option<&ListItem<'a, C, T>> {
+        match self.get_active_submenu().data() {
+            MenuItems::List(list) => Some(list),
+            _ => None,
+        }
+    }
+
+    /// Number of rows of the active menu, or entries of the active list
+    fn active_item_count(&self) -> usize {
+        match self.active_list() {
+            Some(list) => list.source().len(),
+            None => self.get_active_submenu().iter().count(),
+        }
+    }
+
+    /// Go back to the menu above the one shown, as selecting a back item does. Lists have no
+    /// back item of their own, so this is how they are left.
+    pub fn navigate_back(&mut self) {
+        if self.help_page.is_some() {
+            self.close_help();
+            return;
+        }
+        if !self.navigation_path.indices().is_empty() {
+            self.navigate_to_parent();
+        }
+    }
+
+    /// Read the length of the shown list again after its source changed. The highlight stays on
+    /// the same index, or moves to the last entry if the list got shorter.
+    pub fn refresh_list(&mut self) {
+        if self.active_list().is_some() {
+            self.menu_state.update_item_count(self.active_item_count());
+            self.mark_dirty(DirtyRegions::mark_everything);
+        }
+    }
+
+    fn navigate_to_menu(&mut self, target: Tree<MenuItems<'a, C, T, X>>) {
         self.active_submenu_node = Some(target);
         self.menu_state = MenuState::new();
-        let active_tree = self.get_active_submenu();
-        self.menu_state
-            .update_item_count(active_tree.iter().count());
+        self.menu_state.update_item_count(self.active_item_count());
+        self.mark_dirty(DirtyRegions::mark_everything);
     }
 
-    fn navigate_to_selected_submenu(&mut self) {
+    /// Returns false if the submenu is nested too deep to open, see [`MAX_MENU_DEPTH`]
+    fn navigate_to_selected_submenu(&mut self) -> bool {
+        self.populate_selected_submenu();
         let highlighted_item = self.menu_state.highlighted_item();
-        if let Some(item) = self.get_active_submenu().iter().nth(highlighted_item) {
-            self.active_submenu_node = Some(item.deep_clone());
-            self.menu_state = MenuState::new();
-            let active_tree = self.get_active_submenu();
-            self.menu_state
-                .update_item_count(active_tree.iter().count());
+        let Some(submenu) = self
+            .get_active_submenu()
+            .iter()
+            .nth(highlighted_item)
+            .map(|item| item.deep_clone())
+        else {
+            return false;
+        };
+        if !self.navigation_path.push(highlighted_item) {
+            return false;
+        }
+        self.navigate_to_menu(submenu);
+        true
+    }
+
+    fn navigate_to_parent(&mut self) {
+        self.navigation_path.pop();
+        if self.navigation_path.indices().is_empty() {
+            self.navigate_to_root();
+            return;
+        }
+
+        let mut node = self.menu_tree_root.root();
+        for &index in self.navigation_path.indices() {
+            match node.iter().nth(index) {
+                Some(child) => node = child,
+                None => {
+                    self.navigate_to_root();
+                    return;
+                }
+            }
         }
+        let parent_menu = node.deep_clone();
+        self.navigate_to_menu(parent_menu);
     }
 
     fn navigate_to_root(&mut self) {
         self.active_submenu_node = None;
+        self.navigation_path.clear();
         self.menu_state = MenuState::new();
-        let active_tree = self.get_active_submenu();
-        self.menu_state
-            .update_item_count(active_tree.iter().count());
+        self.menu_state.update_item_count(self.active_item_count());
+        self.mark_dirty(DirtyRegions::mark_everything);
+    }
+
+    /// Open a full-screen page showing the help text of the highlighted item, if it has any.
+    /// While the page is open up and down scroll it and selecting closes it again.
+    pub fn show_help(&mut self) -> bool {
+        let has_help_text = self
+            .get_active_submenu()
+            .iter()
+            .nth(self.effective_highlighted_item())
+            .is_some_and(|item| item.data().help_text().is_some());
+        if has_help_text {
+            self.help_page = Some(HelpPage::new());
+            self.mark_dirty(DirtyRegions::mark_everything);
+        }
+        has_help_text
+    }
+
+    /// Close the help page, returning to the item it was opened from
+    pub fn close_help(&mut self) {
+        if self.help_page.take().is_some() {
+            self.mark_dirty(DirtyRegions::mark_everything);
+        }
+    }
+
+    pub fn is_help_open(&self) -> bool {
+        self.help_page.is_some()
     }
 
     pub fn select_item(&mut self) -> Option<SelectedData<T>> {
+        if self.help_page.is_some() {
+            self.close_help();
+            return None;
+        }
         let highlighted_item = self.menu_state.highlighted_item();
+        if let Some(list) = self.active_list() {
+            let index = highlighted_item;
+            return (index < list.source().len()).then(|| SelectedData::ListEntry {
+                id: list.id(),
+                index,
+            });
+        }
 
         let active_tree = self.get_mut_active_submenu();
         if let Some(item) = active_tree.iter_mut().nth(highlighted_item) {
             let selection_result;
-            // I seem to be missing something potentially? Behaviour doesn't seem to match the tree
-            // crate examples, but they use simple types. In any case we don't move the memory, and
-            // it remains valid so doesn't violate the Pin invariants.
-            unsafe {
-                let item = Pin::into_inner_unchecked(item);
+            {
+                let item = unpin(item);
+                let was_editing = item.data().is_editing();
                 selection_result = item.data_mut().selected();
+                let editing = item.data().is_editing();
+                let chosen_radio = match item.data() {
+                    MenuItems::Radio(radio) => Some(*radio),
+                    _ => None,
+                };
+                self.mark_dirty(|dirty_regions| {
+                    dirty_regions.mark_item(highlighted_item);
+                    // The footer hints change between editing and navigating
+                    if editing != was_editing {
+                        dirty_regions.mark_footer();
+                    }
+                });
+                // Changed values, and wrong codes for locked submenus, must outlast this menu
+                self.store_active_item(highlighted_item);
+                // Starting to edit has nothing to report yet
+                if editing {
+                    return None;
+                }
 
+                if let Some(radio) = chosen_radio {
+                    self.choose_radio_option(highlighted_item, &radio);
+                }
                 match selection_result {
-                    SelectedData::Submenu { id: _ } => self.navigate_to_selected_submenu(),
-                    SelectedData::Back { id: _ } => {
-                        let active_menu = self.get_active_submenu();
-                        if let Some(parent_menu) = active_menu.parent().as_mut() {
-                            self.navigate_to_menu(parent_menu.deep_clone());
-                        } else {
-                            self.navigate_to_root();
-                        }
+                    SelectedData::Submenu { id } if !self.navigate_to_selected_submenu() => {
+                        return Some(SelectedData::TooDeep { id });
                     }
+                    SelectedData::Back { id: _ } => self.navigate_to_parent(),
                     _ => {}
                 }
             }
@@ -221,102 +708,1205 @@ where
         None
     }
 
-    fn draw_menu<D>(
-        &self,
-        display: &mut D,
-        menu_tree: &Tree<MenuItems<'_, C, T>>,
-    ) -> Result<(), D::Error>
-    where
-        D: DrawTarget<Color = C>,
-    {
-        let display_area = display.bounding_box();
-        display.clear(self.menu_style.menu_background_color)?;
-        let header = menu_tree.data();
-        let header_height = self.menu_style.heading_character_style.line_height();
-        Text::with_baseline(
-            header.label(),
-            Point::zero(),
-            self.menu_style.heading_character_style,
-            Baseline::Top,
-        )
-        .draw(display)?;
-
-        let mut remaining_item_area = display_area
-            .resized_height(display_area.size().height - header_height, AnchorY::Bottom);
+    /// Copy the item at `index` of the active menu back into the menu structure. The active menu
+    /// is a copy made when it was entered, so changes to its items are otherwise lost on leaving.
+    fn store_active_item(&mut self, index: usize) {
+        let Some(active_tree) = self.active_submenu_node.as_ref() else {
+            // The root menu is the menu structure itself
+            return;
+        };
+        let Some(item) = active_tree.iter().nth(index).map(|item| *item.data()) else {
+            return;
+        };
+        if let Some(stored) = self.stored_active_item_mut(index) {
+            *stored.data_mut() = item;
+        }
+    }
 
-        // This is a fudgy workaround to the problem of starting menus with section headers which
-        // are un-highlightable. Once user scrolls once the menu state catches up. We need a proper
-        // builder to handle this particular edge case nicely and setup the menu state properly.
-        let mut highlighted_item = self.menu_state.highlighted_item();
-        while let Some(item) = self.get_active_submenu().iter().nth(highlighted_item) {
-            if let MenuItems::Section(_) = item.data() {
-                highlighted_item += 1
-            } else {
-                break;
-            }
+    /// Item `index` of the active menu as kept in the menu structure, found by following the
+    /// navigation path down from the root
+    fn stored_active_item_mut(
+        &mut self,
+        index: usize,
+    ) -> Option<&mut Node<MenuItems<'a, C, T, X>>> {
+        let mut node = unpin(self.menu_tree_root.root_mut());
+        for &child_index in self.navigation_path.indices() {
+            node = unpin(node.iter_mut().nth(child_index)?);
         }
+        node.iter_mut().nth(index).map(unpin)
+    }
 
-        let mut skip_count = 0;
-        if highlighted_item > 1 {
-            skip_count = highlighted_item - 1;
+    /// Have the highlighted submenu rebuild its items with its populate callback, if it has one,
+    /// before it is entered
+    fn populate_selected_submenu(&mut self) {
+        let highlighted_item = self.menu_state.highlighted_item();
+        let highlighted = self
+            .get_active_submenu()
+            .iter()
+            .nth(highlighted_item)
+            .map(|item| *item.data());
+        let Some(MenuItems::Submenu(submenu)) = highlighted else {
+            return;
+        };
+        let Some(populate) = submenu.populate() else {
+            return;
+        };
+        let mut items = Menu::with_custom_items(submenu.label(), submenu.id(), self.menu_style);
+        populate(&mut items);
+
+        // Going back up follows the navigation path through the menu structure, so the new items
+        // go there as well as into the copy of the active menu
+        if let Some(stored) = self.stored_active_item_mut(highlighted_item) {
+            Self::replace_children(stored, &items);
         }
-        if highlighted_item == self.menu_state.item_count() && self.menu_state.item_count() >= 2 {
-            skip_count = highlighted_item - 2;
+        if let Some(active_tree) = self.active_submenu_node.as_mut() {
+            if let Some(shown) = active_tree.iter_mut().nth(highlighted_item) {
+                Self::replace_children(unpin(shown), &items);
+            }
         }
+    }
 
-        let menu_iter = menu_tree.iter().skip(skip_count);
+    /// Replace the children of `node` with copies of the items of `menu`
+    fn replace_children(node: &mut Node<MenuItems<'a, C, T, X>>, menu: &Menu<'a, C, T, X>) {
+        while node.pop_front().is_some() {}
+        for item in menu.menu_tree_root.iter() {
+            node.push_back(item.deep_clone());
+        }
+    }
 
-        for (id, menu_item) in menu_iter.enumerate() {
-            let item_height = menu_item.data().size().height;
-            if item_height > remaining_item_area.size().height {
-                break;
+    /// Move the chosen mark of a radio group to `chosen`, the row at `chosen_item`. The rows of a
+    /// group are next to each other, so the rest of the group sits either side of it.
+    fn choose_radio_option(&mut self, chosen_item: usize, chosen: &RadioItem<'a, C, T>) {
+        let first_item = chosen_item.saturating_sub(chosen.option_index());
+        let active_tree = self.get_mut_active_submenu();
+        let mut group_size = 0;
+        for item in active_tree.iter_mut().skip(first_item) {
+            let item = unpin(item);
+            match item.data_mut() {
+                MenuItems::Radio(radio) if radio.same_group(chosen, group_size) => {
+                    radio.choose(chosen.option_index());
+                    group_size += 1;
+                }
+                _ => break,
             }
-
-            let mut item_display = display.cropped(&remaining_item_area);
-            if id + skip_count == highlighted_item {
-                menu_item.data().draw_highlighted(&mut item_display)?;
-            } else {
-                menu_item.data().draw(&mut item_display)?;
+        }
+        // Every row of the group changed, not just the chosen one
+        for index in first_item..first_item + group_size {
+            self.store_active_item(index);
+        }
+        self.mark_dirty(|dirty_regions| {
+            for index in first_item..first_item + group_size {
+                dirty_regions.mark_item(index);
             }
+        });
+    }
 
-            remaining_item_area = remaining_item_area.resized_height(
-                remaining_item_area.size().height - item_height,
-                AnchorY::Bottom,
-            );
+    /// Move the indeterminate progress bars of the active menu along. Call regularly, such as
+    /// once per frame, to animate them.
+    pub fn tick(&mut self) {
+        let mut dirty_regions = self.dirty_regions.get();
+        let active_tree = self.get_mut_active_submenu();
+        for (index, item) in active_tree.iter_mut().enumerate() {
+            let item = unpin(item);
+            if let MenuItems::Progress(progress) = item.data_mut() {
+                if progress.tick() {
+                    dirty_regions.mark_item(index);
+                }
+            }
         }
+        self.dirty_regions.set(dirty_regions);
+    }
 
-        Ok(())
+    /// Whether the highlighted item is being edited, with navigation changing its value rather
+    /// than moving the highlight
+    pub fn is_editing(&self) -> bool {
+        self.get_active_submenu()
+            .iter()
+            .nth(self.menu_state.highlighted_item())
+            .is_some_and(|item| item.data().is_editing())
     }
-}
 
-impl<C, T> Drawable for Menu<'_, C, T>
-where
-    C: PixelColor,
-    T: Copy + Clone + Sized,
-{
-    type Color = C;
-    type Output = ();
+    /// The latest change made while editing an item that reports every change, such as a
+    /// [`SliderItem`] with [`SliderUpdates::EveryChange`](items::slider::SliderUpdates). Check
+    /// after each navigation call to follow the value as it is adjusted.
+    pub fn take_edit_change(&mut self) -> Option<SelectedData<T>> {
+        self.edit_change.take()
+    }
 
-    fn draw<D>(&self, display: &mut D) -> Result<Self::Output, D::Error>
+    /// Whether anything changed since the menu was last drawn
+    pub fn needs_redraw(&self) -> bool {
+        !self.dirty_regions.get().is_clean()
+    }
+
+    /// Redraw only the parts of the menu that changed since it was last drawn: the rows the
+    /// highlight moved between, items whose value changed and the header and footer if their
+    /// content changed. Falls back to a full redraw after navigating to another menu, changing the
+    /// style or layout, or scrolling the list.
+    ///
+    /// The returned report lists the areas that were drawn, so displays that support it only need
+    /// to refresh those.
+    pub fn draw_changes<D>(&self, display: &mut D) -> Result<DrawReport, D::Error>
     where
-        D: DrawTarget<Color = Self::Color>,
+        D: DrawTarget<Color = C>,
     {
-        let active_tree = self.get_active_submenu();
-        self.draw_menu(display, active_tree)?;
+        let viewport = self.viewport.unwrap_or_else(|| display.bounding_box());
+        let mut report = DrawReport::new(viewport, self.rotation);
+        let dirty_regions = self.dirty_regions.get();
+        if dirty_regions.is_clean() {
+            return Ok(report);
+        }
+        if self.count_update() {
+            report.request_full_refresh();
+        }
+        // Rows can't be erased without clearing them to the background
+        if dirty_regions.everything() || !self.clear_background || self.help_page.is_some() {
+            self.draw_menu(display, self.get_active_submenu())?;
+            report.add_viewport();
+            return Ok(report);
+        }
 
-        Ok(())
-    }
-}
+        let display = &mut display.clipped(&viewport);
+        let display = &mut display.cropped(&viewport);
+        let display = &mut RotatedTarget::new(display, self.rotation);
+        let menu_tree = self.get_active_submenu();
+        let background_color = self.menu_style.menu_background_color;
+        let areas = self.menu_areas(display.bounding_box());
+        let item_area = areas.items;
+        let highlighted_item = self.effective_highlighted_item();
 
-impl<'a, C, T> From<Menu<'a, C, T>> for Tree<MenuItems<'a, C, T>>
-where
-    C: PixelColor,
-    T: Copy + Clone + Sized,
-{
-    fn from(menu: Menu<'a, C, T>) -> Tree<MenuItems<'a, C, T>> {
-        menu.menu_tree_root
-    }
-}
+        if dirty_regions.header() {
+            display.fill_solid(&areas.header, background_color)?;
+            self.draw_header_area(display, areas.header, menu_tree)?;
+            report.add_region(areas.header);
+        }
+        if dirty_regions.help_panel() {
+            display.fill_solid(&areas.help_panel, background_color)?;
+            self.draw_help_panel(display, areas.help_panel, highlighted_item)?;
+            report.add_region(areas.help_panel);
+        }
+        if dirty_regions.footer() && self.menu_style.show_footer {
+            display.fill_solid(&areas.footer, background_color)?;
+            self.draw_footer_area(display, areas.footer, highlighted_item)?;
+            report.add_region(areas.footer);
+        }
+
+        let scroll_offset = self.list_scroll_offset(highlighted_item, item_area);
+        if dirty_regions.any_items() {
+            match self.get_active_layout() {
+                MenuLayout::List
+                    if !dirty_regions.all_items()
+                        && scroll_offset == dirty_regions.scroll_offset() =>
+                {
+                    self.draw_list(
+                        display,
+                        menu_tree,
+                        item_area,
+                        highlighted_item,
+                        Some((dirty_regions, &mut report)),
+                    )?
+                }
+                _ => {
+                    display.fill_solid(&item_area, background_color)?;
+                    self.draw_items(display, menu_tree, item_area, highlighted_item)?;
+                    report.add_region(item_area);
+                }
+            }
+        }
+
+        self.dirty_regions.set(DirtyRegions::drawn(scroll_offset));
+        Ok(report)
+    }
+
+    /// Count a draw in e-paper mode, returning true once it is time for a full refresh
+    fn count_update(&self) -> bool {
+        match self.render_mode {
+            RenderMode::EPaper {
+                full_refresh_interval,
+            } if full_refresh_interval > 0 => {
+                let partial_updates = self.partial_updates.get() + 1;
+                if partial_updates >= full_refresh_interval {
+                    self.partial_updates.set(0);
+                    true
+                } else {
+                    self.partial_updates.set(partial_updates);
+                    false
+                }
+            }
+            _ => false,
+        }
+    }
+
+    fn mark_dirty<F>(&self, mark: F)
+    where
+        F: FnOnce(&mut DirtyRegions),
+    {
+        let mut dirty_regions = self.dirty_regions.get();
+        mark(&mut dirty_regions);
+        self.dirty_regions.set(dirty_regions);
+    }
+
+    fn draw_menu<D>(
+        &self,
+        display: &mut D,
+        menu_tree: &Tree<MenuItems<'a, C, T, X>>,
+    ) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let viewport = self.viewport.unwrap_or_else(|| display.bounding_box());
+        let display = &mut display.clipped(&viewport);
+        let display = &mut display.cropped(&viewport);
+        let display = &mut RotatedTarget::new(display, self.rotation);
+        if self.clear_background {
+            display.clear(self.menu_style.menu_background_color)?;
+        }
+        let areas = self.menu_areas(display.bounding_box());
+        let highlighted_item = self.effective_highlighted_item();
+
+        if let Some(help_page) = &self.help_page {
+            self.draw_help_page(display, &areas, highlighted_item, help_page)?;
+            self.dirty_regions.set(DirtyRegions::drawn(0));
+            return Ok(());
+        }
+
+        self.draw_header_area(display, areas.header, menu_tree)?;
+        self.draw_help_panel(display, areas.help_panel, highlighted_item)?;
+        if self.menu_style.show_footer {
+            self.draw_footer_area(display, areas.footer, highlighted_item)?;
+        }
+        self.draw_items(display, menu_tree, areas.items, highlighted_item)?;
+
+        self.dirty_regions.set(DirtyRegions::drawn(
+            self.list_scroll_offset(highlighted_item, areas.items),
+        ));
+        Ok(())
+    }
+
+    /// Split the display into the header, item, help panel and footer areas, top to bottom
+    fn menu_areas(&self, display_area: Rectangle) -> MenuAreas {
+        let header_height = self.menu_style.heading_character_style.line_height();
+        let help_panel_height = self.menu_style.help_panel_height();
+        let footer_height = self.menu_style.footer_height();
+        let display_height = display_area.size().height;
+        let item_height =
+            display_height.saturating_sub(header_height + help_panel_height + footer_height);
+        let header = display_area.resized_height(header_height, AnchorY::Top);
+        let items = Rectangle::new(
+            header.top_left + Point::new(0, header_height as i32),
+            Size::new(display_area.size().width, item_height),
+        );
+        let help_panel = Rectangle::new(
+            items.top_left + Point::new(0, item_height as i32),
+            Size::new(display_area.size().width, help_panel_height),
+        );
+        let footer = display_area.resized_height(footer_height, AnchorY::Bottom);
+        MenuAreas {
+            header,
+            items,
+            help_panel,
+            footer,
+        }
+    }
+
+    /// Draw the help text of the highlighted item into the help panel below the items, below a
+    /// separator line if the style has one
+    fn draw_help_panel<D>(
+        &self,
+        display: &mut D,
+        help_panel_area: Rectangle,
+        highlighted_item: usize,
+    ) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        if help_panel_area.is_zero_sized() {
+            return Ok(());
+        }
+        if let (Some(separator_color), Some(bottom_right)) = (
+            self.menu_style.separator_color,
+            help_panel_area.bottom_right(),
+        ) {
+            Line::new(
+                help_panel_area.top_left,
+                Point::new(bottom_right.x, help_panel_area.top_left.y),
+            )
+            .into_styled(PrimitiveStyle::with_stroke(separator_color, 1))
+            .draw(display)?;
+        }
+
+        let help_text = self
+            .get_active_submenu()
+            .iter()
+            .nth(highlighted_item)
+            .and_then(|item| item.data().help_text());
+        if let Some(help_text) = help_text {
+            // One pixel for the separator line above the text
+            let text_area = help_panel_area.resized_height(
+                help_panel_area.size.height.saturating_sub(1),
+                AnchorY::Bottom,
+            );
+            help::draw_wrapped(
+                display,
+                text_area,
+                help_text,
+                0,
+                self.menu_style.description_character_style,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Draw the help page of the highlighted item in place of the menu: its label in the header
+    /// and its help text filling the space down to the footer
+    fn draw_help_page<D>(
+        &self,
+        display: &mut D,
+        areas: &MenuAreas,
+        highlighted_item: usize,
+        help_page: &HelpPage,
+    ) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let Some(item) = self.get_active_submenu().iter().nth(highlighted_item) else {
+            return Ok(());
+        };
+        header::draw_header(
+            display,
+            areas.header,
+            &[item.data().label()],
+            &self.status_icons,
+            &self.menu_style,
+        )?;
+
+        let text_style = self.menu_style.item_character_style;
+        let text_area = Rectangle::new(
+            areas.items.top_left,
+            areas.items.size + Size::new(0, areas.help_panel.size.height),
+        );
+        let line_count = help::draw_wrapped(
+            display,
+            text_area,
+            item.data().help_text().unwrap_or(""),
+            help_page.first_line(),
+            text_style,
+        )?;
+        help_page.set_drawn_lines(
+            line_count,
+            (text_area.size.height / text_style.line_height().max(1)) as usize,
+        );
+
+        if self.menu_style.show_footer {
+            footer::draw_footer(
+                display,
+                areas.footer,
+                SoftKeyHints::new("", "Close", ""),
+                &self.menu_style,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// This is a fudgy workaround to the problem of starting menus with section headers which
+    /// are un-highlightable. Once user scrolls once the menu state catches up. We need a proper
+    /// builder to handle this particular edge case nicely and setup the menu state properly.
+    fn effective_highlighted_item(&self) -> usize {
+        let mut highlighted_item = self.menu_state.highlighted_item();
+        while let Some(item) = self.get_active_submenu().iter().nth(highlighted_item) {
+            if !item.data().is_highlightable() {
+                highlighted_item += 1
+            } else {
+                break;
+            }
+        }
+        highlighted_item
+    }
+
+    fn draw_header_area<D>(
+        &self,
+        display: &mut D,
+        header_area: Rectangle,
+        menu_tree: &Tree<MenuItems<'a, C, T, X>>,
+    ) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        if self.menu_style.header_style.breadcrumbs {
+            let mut breadcrumbs = [""; MAX_MENU_DEPTH + 1];
+            let mut breadcrumb_count = 0;
+            let mut node = self.menu_tree_root.root();
+            breadcrumbs[0] = node.data().label();
+            for &index in self.navigation_path.indices() {
+                let Some(child) = node.iter().nth(index) else {
+                    break;
+                };
+                node = child;
+                breadcrumb_count += 1;
+                breadcrumbs[breadcrumb_count] = node.data().label();
+            }
+            header::draw_header(
+                display,
+                header_area,
+                &breadcrumbs[..=breadcrumb_count],
+                &self.status_icons,
+                &self.menu_style,
+            )
+        } else {
+            header::draw_header(
+                display,
+                header_area,
+                &[menu_tree.data().label()],
+                &self.status_icons,
+                &self.menu_style,
+            )
+        }
+    }
+
+    fn draw_footer_area<D>(
+        &self,
+        display: &mut D,
+        footer_area: Rectangle,
+        highlighted_item: usize,
+    ) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        footer::draw_footer(
+            display,
+            footer_area,
+            self.soft_key_hints(highlighted_item),
+            &self.menu_style,
+        )
+    }
+
+    fn draw_items<D>(
+        &self,
+        display: &mut D,
+        menu_tree: &Tree<MenuItems<'a, C, T, X>>,
+        item_area: Rectangle,
+        highlighted_item: usize,
+    ) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        match self.get_active_layout() {
+            MenuLayout::List => {
+                self.draw_list(display, menu_tree, item_area, highlighted_item, None)
+            }
+            MenuLayout::Grid { columns, rows } => layout::draw_grid(
+                &mut display.cropped(&item_area),
+                menu_tree,
+                highlighted_item,
+                columns,
+                rows,
+                &self.menu_style,
+            ),
+            MenuLayout::Carousel => layout::draw_carousel(
+                &mut display.cropped(&item_area),
+                menu_tree,
+                highlighted_item,
+                &self.menu_style,
+            ),
+        }
+    }
+
+    fn soft_key_hints(&self, highlighted_item: usize) -> SoftKeyHints {
+        match self.get_active_submenu().iter().nth(highlighted_item) {
+            Some(item) => {
+                let mut hints = item.data().soft_key_hints();
+                // There's nothing to go back to from the root menu
+                if item.data().soft_keys().is_none()
+                    && !item.data().is_editing()
+                    && self.navigation_path.indices().is_empty()
+                {
+                    hints.left = "";
+                }
+                hints
+            }
+            None => match self.active_list() {
+                Some(list) if !list.source().is_empty() => SoftKeyHints::new("Back", "Select", ""),
+                Some(_) => SoftKeyHints::new("Back", "", ""),
+                None => SoftKeyHints::new("", "", ""),
+            },
+        }
+    }
+
+    /// Index of the first item shown by the list layout. The item above the highlighted one is
+    /// kept in view when both fit, but never at the cost of the highlighted item itself.
+    fn list_scroll_offset(&self, highlighted_item: usize, item_area: Rectangle) -> usize {
+        if let RenderMode::EPaper { .. } = self.render_mode {
+            return self.list_page_start(highlighted_item, item_area);
+        }
+
+        let mut skip_count = 0;
+        if highlighted_item > 1 {
+            skip_count = highlighted_item - 1;
+        }
+        if highlighted_item == self.menu_state.item_count() && self.menu_state.item_count() >= 2 {
+            skip_count = highlighted_item - 2;
+        }
+
+        // Rows can be taller than one line, so scroll on until the highlighted one fits
+        let row_height = |index: usize| match self.active_list() {
+            Some(list) => list.entry_height(),
+            None => self
+                .get_active_submenu()
+                .iter()
+                .nth(index)
+                .map_or(0, |item| item.data().height_in(item_area.size.width)),
+        };
+        let mut shown_height: u32 = (skip_count..=highlighted_item).map(row_height).sum();
+        while skip_count < highlighted_item && shown_height > item_area.size.height {
+            shown_height -= row_height(skip_count);
+            skip_count += 1;
+        }
+        skip_count
+    }
+
+    /// Index of the first item on the page of the list holding the highlighted item, filling each
+    /// page with as many whole items as fit in `item_area`
+    fn list_page_start(&self, highlighted_item: usize, item_area: Rectangle) -> usize {
+        if let Some(list) = self.active_list() {
+            let page_entries = (item_area.size.height / list.entry_height().max(1)).max(1) as usize;
+            return highlighted_item - highlighted_item % page_entries;
+        }
+        let mut page_start = 0;
+        let mut page_height = 0;
+        for (index, item) in self.get_active_submenu().iter().enumerate() {
+            let item_height = item.data().height_in(item_area.size.width);
+            if index > page_start && page_height + item_height > item_area.size.height {
+                page_start = index;
+                page_height = 0;
+            }
+            if index == highlighted_item {
+                break;
+            }
+            page_height += item_height;
+        }
+        page_start
+    }
+
+    /// Draw the visible list rows. With `dirty_rows` only the rows marked dirty are drawn, each
+    /// cleared first and added to the report; otherwise every row is drawn onto the already cleared
+    /// item area.
+    fn draw_list<D>(
+        &self,
+        display: &mut D,
+        menu_tree: &Tree<MenuItems<'a, C, T, X>>,
+        item_area: Rectangle,
+        highlighted_item: usize,
+        mut dirty_rows: Option<(DirtyRegions, &mut DrawReport)>,
+    ) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        if let MenuItems::List(list) = menu_tree.data() {
+            return self.draw_list_entries(display, list, item_area, highlighted_item, dirty_rows);
+        }
+        let mut remaining_item_area = item_area;
+        let skip_count = self.list_scroll_offset(highlighted_item, item_area);
+
+        let menu_iter = menu_tree.iter().skip(skip_count);
+
+        for (id, menu_item) in menu_iter.enumerate() {
+            let index = id + skip_count;
+            let item_height = menu_item.data().height_in(item_area.size.width);
+            if item_height > remaining_item_area.size().height {
+                break;
+            }
+
+            let redraw_row = match dirty_rows.as_mut() {
+                Some((dirty_regions, report)) => {
+                    let dirty_row = dirty_regions.item(index);
+                    if dirty_row {
+                        let row_area =
+                            remaining_item_area.resized_height(item_height, AnchorY::Top);
+                        display.fill_solid(&row_area, self.menu_style.menu_background_color)?;
+                        report.add_region(row_area);
+                    }
+                    dirty_row
+                }
+                None => true,
+            };
+            if redraw_row {
+                let mut item_display = display.cropped(&remaining_item_area);
+                if index == highlighted_item {
+                    menu_item.data().draw_highlighted(&mut item_display)?;
+                } else {
+                    menu_item.data().draw(&mut item_display)?;
+                }
+            }
+
+            remaining_item_area = remaining_item_area.resized_height(
+                remaining_item_area.size().height - item_height,
+                AnchorY::Bottom,
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Draw the visible entries of an open list the way `draw_list` draws rows. Labels are read
+    /// from the source only for the entries in view.
+    fn draw_list_entries<D>(
+        &self,
+        display: &mut D,
+        list: &ListItem<'_, C, T>,
+        item_area: Rectangle,
+        highlighted_item: usize,
+        mut dirty_rows: Option<(DirtyRegions, &mut DrawReport)>,
+    ) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let entry_height = list.entry_height();
+        let mut remaining_item_area = item_area;
+        let skip_count = self.list_scroll_offset(highlighted_item, item_area);
+
+        for index in skip_count..list.source().len() {
+            if entry_height > remaining_item_area.size().height {
+                break;
+            }
+            let row_area = remaining_item_area.resized_height(entry_height, AnchorY::Top);
+
+            let redraw_row = match dirty_rows.as_mut() {
+                Some((dirty_regions, report)) => {
+                    let dirty_row = dirty_regions.item(index);
+                    if dirty_row {
+                        display.fill_solid(&row_area, self.menu_style.menu_background_color)?;
+                        report.add_region(row_area);
+                    }
+                    dirty_row
+                }
+                None => true,
+            };
+            if redraw_row {
+                list.draw_entry(
+                    &mut display.cropped(&row_area),
+                    index,
+                    index == highlighted_item,
+                )?;
+            }
+
+            remaining_item_area = remaining_item_area.resized_height(
+                remaining_item_area.size().height - entry_height,
+                AnchorY::Bottom,
+            );
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, C, T, X> Menu<'a, C, T, X>
+where
+    C: PixelColor,
+    T: Clone + Copy + Sized + PartialEq,
+    X: CustomMenuItem<'a, C, T>,
+{
+    /// Set the icon drawn to the left of the label of the item with the given id
+    pub fn set_icon(&mut self, id: T, icon: Option<MenuIcon<'a, C>>) {
+        self.update_item(id, |item| item.set_icon(icon));
+    }
+
+    /// Override parts of the menu style for the item with the given id
+    pub fn set_style_override(&mut self, id: T, style_override: Option<StyleOverride<'a, C>>) {
+        self.update_item(id, |item| item.set_style_override(style_override));
+    }
+
+    /// Replace the footer soft key hints shown while the item with the given id is highlighted
+    pub fn set_soft_keys(&mut self, id: T, soft_keys: Option<SoftKeyHints>) {
+        self.update_item(id, |item| item.set_soft_keys(soft_keys));
+    }
+
+    /// Change how the checkbox with the given id draws its state, or go back to the menu style
+    /// with `None`
+    pub fn set_checkbox_style(&mut self, id: T, checkbox_style: Option<CheckboxStyle>) {
+        self.update_item(id, |item| item.set_checkbox_style(checkbox_style));
+    }
+
+    /// Set the longer explanation shown in the help panel or on the help page of the item with
+    /// the given id
+    pub fn set_help_text(&mut self, id: T, help_text: Option<&'static str>) {
+        self.update_item(id, |item| item.set_help_text(help_text));
+    }
+
+    /// Set a line of explanation drawn beneath the label of the item with the given id
+    pub fn set_description(&mut self, id: T, description: Option<&'static str>) {
+        self.update_item(id, |item| item.set_description(description));
+    }
+
+    /// Update the progress bar with the given id. Only its row is redrawn, so this is cheap to
+    /// call often.
+    pub fn set_progress(&mut self, id: T, progress: Progress) {
+        self.apply_to_item(id, |item| {
+            if let MenuItems::Progress(item) = item {
+                item.set_progress(progress);
+            }
+        });
+        let shown_index = self
+            .get_active_submenu()
+            .iter()
+            .position(|item| item.data().id() == id);
+        if let Some(index) = shown_index {
+            self.mark_dirty(|dirty_regions| dirty_regions.mark_item(index));
+        }
+    }
+
+    /// Ask for a code or a grant before opening the submenu with the given id, or open it freely
+    /// with `None`. Refusals are reported as [`SelectedData::AccessDenied`]. Returns `false` if
+    /// there is no submenu with the given id.
+    pub fn set_submenu_lock(&mut self, id: T, lock: Option<SubmenuLock<'a>>) -> bool {
+        if !matches!(
+            Self::find_item(self.menu_tree_root.root(), id),
+            Some(MenuItems::Submenu(_))
+        ) {
+            return false;
+        }
+        self.update_item(id, |item| {
+            if let MenuItems::Submenu(item) = item {
+                item.set_lock(lock);
+            }
+        });
+        true
+    }
+
+    /// Lock out the submenu with the given id after this many wrong codes in a row, until
+    /// [`reset_submenu_lock`](Self::reset_submenu_lock)
+    pub fn set_max_attempts(&mut self, id: T, max_attempts: Option<u32>) {
+        self.apply_to_item(id, |item| {
+            if let MenuItems::Submenu(item) = item {
+                item.set_max_attempts(max_attempts);
+            }
+        });
+    }
+
+    /// Wrong codes entered for the submenu with the given id since it last opened
+    pub fn failed_attempts(&self, id: T) -> u32 {
+        match Self::find_item(self.menu_tree_root.root(), id) {
+            Some(MenuItems::Submenu(item)) => item.failed_attempts(),
+            _ => 0,
+        }
+    }
+
+    /// Whether the submenu with the given id ran out of attempts and refuses to open
+    pub fn is_locked_out(&self, id: T) -> bool {
+        match Self::find_item(self.menu_tree_root.root(), id) {
+            Some(MenuItems::Submenu(item)) => item.is_locked_out(),
+            _ => false,
+        }
+    }
+
+    /// Forget the wrong codes entered for the submenu with the given id, lifting a lockout
+    pub fn reset_submenu_lock(&mut self, id: T) {
+        self.apply_to_item(id, |item| {
+            if let MenuItems::Submenu(item) = item {
+                item.reset_attempts();
+            }
+        });
+        let shown_index = self
+            .get_active_submenu()
+            .iter()
+            .position(|item| item.data().id() == id);
+        if let Some(index) = shown_index {
+            self.mark_dirty(|dirty_regions| dirty_regions.mark_item(index));
+        }
+    }
+
+    /// Insert `item`, or a whole submenu, just before the item with id `before`, wherever it is in
+    /// the menu. Returns false if there is no such item. A radio group counts as one item, so
+    /// nothing is ever inserted between its rows.
+    pub fn insert_before(
+        &mut self,
+        before: T,
+        item: impl Into<Tree<MenuItems<'a, C, T, X>>>,
+    ) -> bool {
+        self.insert_item(before, Placement::Before, item.into())
+    }
+
+    /// Insert `item`, or a whole submenu, just after the item with id `after`, wherever it is in
+    /// the menu. Returns false if there is no such item. A radio group counts as one item, so
+    /// nothing is ever inserted between its rows.
+    pub fn insert_after(
+        &mut self,
+        after: T,
+        item: impl Into<Tree<MenuItems<'a, C, T, X>>>,
+    ) -> bool {
+        self.insert_item(after, Placement::After, item.into())
+    }
+
+    /// Remove the item with the given id, with all its items if it is a submenu or all its rows if
+    /// it is a radio group. Returns false if there is no such item. Removing the shown submenu, or
+    /// one above it, goes back to the closest menu left.
+    pub fn remove_item(&mut self, id: T) -> bool {
+        let position = self.position();
+        let (root, active_root) = self.tree_roots_mut();
+        if Self::detach_all(root, id).has_no_child() {
+            return false;
+        }
+        if let Some(active_root) = active_root {
+            Self::detach_all(active_root, id);
+        }
+        self.restore_position(&position);
+        true
+    }
+
+    /// Move the item with the given id, with all its items if it is a submenu or all its rows if it
+    /// is a radio group, to just before the item with id `before`. Returns false if either is
+    /// missing or `before` is inside the moved submenu.
+    pub fn move_before(&mut self, id: T, before: T) -> bool {
+        self.move_item(id, before, Placement::Before)
+    }
+
+    /// Move the item with the given id, with all its items if it is a submenu or all its rows if it
+    /// is a radio group, to just after the item with id `after`. Returns false if either is
+    /// missing or `after` is inside the moved submenu.
+    pub fn move_after(&mut self, id: T, after: T) -> bool {
+        self.move_item(id, after, Placement::After)
+    }
+
+    /// Remove every item of the submenu with the given id, or of the top level menu when given
+    /// the id of the menu itself. Returns false if there is no such submenu.
+    pub fn clear_submenu(&mut self, id: T) -> bool {
+        let position = self.position();
+        let (root, active_root) = self.tree_roots_mut();
+        let Some(submenu) = Self::find_node_mut(root, id) else {
+            return false;
+        };
+        while submenu.pop_front().is_some() {}
+        if let Some(active_root) = active_root {
+            if let Some(submenu) = Self::find_node_mut(active_root, id) {
+                while submenu.pop_front().is_some() {}
+            }
+        }
+        self.restore_position(&position);
+        true
+    }
+
+    fn insert_item(
+        &mut self,
+        target: T,
+        placement: Placement,
+        item: Tree<MenuItems<'a, C, T, X>>,
+    ) -> bool {
+        let position = self.position();
+        let mut items = Forest::new();
+        items.push_back(item);
+        let shown_items = self.active_submenu_node.as_ref().map(|_| items.clone());
+        let (root, active_root) = self.tree_roots_mut();
+        let Some(target_node) = Self::target_mut(root, target, placement) else {
+            return false;
+        };
+        placement.insert(target_node, items);
+        if let (Some(active_root), Some(shown_items)) = (active_root, shown_items) {
+            if let Some(target_node) = Self::target_mut(active_root, target, placement) {
+                placement.insert(target_node, shown_items);
+            }
+        }
+        self.restore_position(&position);
+        true
+    }
+
+    fn move_item(&mut self, id: T, target: T, placement: Placement) -> bool {
+        if id == target {
+            return false;
+        }
+        let position = self.position();
+        let (root, active_root) = self.tree_roots_mut();
+        if Self::find_child_mut(root, target).is_none() {
+            return false;
+        }
+        let Some(moved_node) = Self::find_child_mut(root, id) else {
+            return false;
+        };
+        // A submenu can't be moved into itself
+        if Self::find_child_mut(moved_node, target).is_some() {
+            return false;
+        }
+        let moved = Self::detach_all(root, id);
+        let Some(target_node) = Self::target_mut(root, target, placement) else {
+            // The target was checked to be there and outside the moved item, but put the item
+            // back at the end rather than lose it
+            root.append(moved);
+            self.restore_position(&position);
+            return false;
+        };
+        let shown_items = moved.clone();
+        placement.insert(target_node, moved);
+
+        // The shown copy of the active menu may hold either end of the move, or both
+        if let Some(active_root) = active_root {
+            let detached = Self::detach_all(active_root, id);
+            let shown_items = if detached.has_no_child() {
+                shown_items
+            } else {
+                detached
+            };
+            if let Some(target_node) = Self::target_mut(active_root, target, placement) {
+                placement.insert(target_node, shown_items);
+            }
+        }
+        self.restore_position(&position);
+        true
+    }
+
+    /// Where the active menu is, by id, so it can be found again after the menu changed shape
+    fn position(&self) -> MenuPosition<T> {
+        let mut path = [None; MAX_MENU_DEPTH];
+        let mut node = self.menu_tree_root.root();
+        for (level, &index) in self.navigation_path.indices().iter().enumerate() {
+            let Some(child) = node.iter().nth(index) else {
+                break;
+            };
+            path[level] = Some(child.data().id());
+            node = child;
+        }
+        let highlighted = match self.active_list() {
+            // List entries are not items, so the highlight stays on the same index
+            Some(_) => None,
+            None => {
+                let menu = self.get_active_submenu();
+                let index = self.menu_state.highlighted_item();
+                menu.iter().nth(index).map(|item| {
+                    let id = item.data().id();
+                    let earlier_rows = menu
+                        .iter()
+                        .take(index)
+                        .filter(|other| other.data().id() == id)
+                        .count();
+                    (id, earlier_rows)
+                })
+            }
+        };
+        MenuPosition { path, highlighted }
+    }
+
+    /// Find the active menu and highlighted item saved in `position` again. If the active menu is
+    /// gone the closest menu above it that is left is shown, and if the highlighted item is gone
+    /// the highlight stays at the same row, moving up if the menu got shorter.
+    fn restore_position(&mut self, position: &MenuPosition<T>) {
+        let depth = self.navigation_path.indices().len();
+        let found = position.path[..depth]
+            .iter()
+            .enumerate()
+            .rev()
+            .find_map(|(level, id)| {
+                let path = Self::path_to(self.menu_tree_root.root(), (*id)?)?;
+                Some((level, path))
+            });
+        match found {
+            // The active menu is still there, possibly somewhere else
+            Some((level, path)) if level + 1 == depth => self.navigation_path = path,
+            Some((_, path)) => {
+                let mut node = self.menu_tree_root.root();
+                for &index in path.indices() {
+                    match node.iter().nth(index) {
+                        Some(child) => node = child,
+                        None => break,
+                    }
+                }
+                let menu = node.deep_clone();
+                self.navigation_path = path;
+                self.navigate_to_menu(menu);
+            }
+            None if depth > 0 => self.navigate_to_root(),
+            None => {}
+        }
+
+        let highlighted_index = position.highlighted.and_then(|(id, earlier_rows)| {
+            self.get_active_submenu()
+                .iter()
+                .enumerate()
+                .filter(|(_, item)| item.data().id() == id)
+                .nth(earlier_rows)
+                .map(|(index, _)| index)
+        });
+        if let Some(index) = highlighted_index {
+            self.menu_state.set_highlighted_item(index);
+        } else if position.highlighted.is_some() {
+            self.close_help();
+        }
+        self.menu_state.update_item_count(self.active_item_count());
+        self.mark_dirty(DirtyRegions::mark_everything);
+    }
+
+    /// Indices of the submenus leading from `node` down to the submenu with the given id
+    fn path_to(node: &Node<MenuItems<'a, C, T, X>>, id: T) -> Option<NavigationPath> {
+        for (index, child) in node.iter().enumerate() {
+            if child.data().id() == id {
+                let mut path = NavigationPath::new();
+                path.push(index);
+                return Some(path);
+            }
+            if let Some(mut path) = Self::path_to(child, id) {
+                return path.push_front(index).then_some(path);
+            }
+        }
+        None
+    }
+
+    /// Apply `update` to the item with the given id and redraw the whole menu, as the change may
+    /// affect the size of the item
+    fn update_item<F>(&mut self, id: T, update: F)
+    where
+        F: Fn(&mut MenuItems<'a, C, T, X>),
+    {
+        self.apply_to_item(id, update);
+        self.mark_dirty(DirtyRegions::mark_everything);
+    }
+
+    /// Apply `update` to the item with the given id, in both the menu structure and the currently
+    /// active submenu. Every row of a radio group shares the group id, so all of them are updated.
+    fn apply_to_item<F>(&mut self, id: T, update: F)
+    where
+        F: Fn(&mut MenuItems<'a, C, T, X>),
+    {
+        let (root, active_root) = self.tree_roots_mut();
+        Self::update_matching(root, id, &update);
+        if let Some(active_root) = active_root {
+            Self::update_matching(active_root, id, &update);
+        }
+    }
+
+    /// Apply `update` to `node` and every node below it with the given id
+    fn update_matching<F>(node: &mut Node<MenuItems<'a, C, T, X>>, id: T, update: &F)
+    where
+        F: Fn(&mut MenuItems<'a, C, T, X>),
+    {
+        if node.data().id() == id {
+            update(node.data_mut());
+        }
+        for child in node.iter_mut() {
+            Self::update_matching(unpin(child), id, update);
+        }
+    }
+
+    fn find_item<'n>(
+        node: &'n Node<MenuItems<'a, C, T, X>>,
+        id: T,
+    ) -> Option<&'n MenuItems<'a, C, T, X>> {
+        if node.data().id() == id {
+            return Some(node.data());
+        }
+        node.iter().find_map(|child| Self::find_item(child, id))
+    }
+
+    /// `node` itself or the node below it with the given id
+    fn find_node_mut<'n>(
+        node: &'n mut Node<MenuItems<'a, C, T, X>>,
+        id: T,
+    ) -> Option<&'n mut Node<MenuItems<'a, C, T, X>>> {
+        if node.data().id() == id {
+            return Some(node);
+        }
+        Self::find_child_mut(node, id)
+    }
+
+    /// The node below `node` with the given id, which unlike `node` itself always has a parent
+    fn find_child_mut<'n>(
+        node: &'n mut Node<MenuItems<'a, C, T, X>>,
+        id: T,
+    ) -> Option<&'n mut Node<MenuItems<'a, C, T, X>>> {
+        Self::nth_child_mut(node, id, &mut 0)
+    }
+
+    /// The node below `node` to put items next to on the given side of the item with id
+    /// `target`. All rows of a radio group share its id, so this is the first row when inserting
+    /// before the group and the last row when inserting after it.
+    fn target_mut<'n>(
+        node: &'n mut Node<MenuItems<'a, C, T, X>>,
+        target: T,
+        placement: Placement,
+    ) -> Option<&'n mut Node<MenuItems<'a, C, T, X>>> {
+        let mut skip = match placement {
+            Placement::Before => 0,
+            Placement::After => Self::count_matching(node, target).checked_sub(1)?,
+        };
+        Self::nth_child_mut(node, target, &mut skip)
+    }
+
+    /// The node below `node` with the given id after skipping `skip` of them
+    fn nth_child_mut<'n>(
+        node: &'n mut Node<MenuItems<'a, C, T, X>>,
+        id: T,
+        skip: &mut usize,
+    ) -> Option<&'n mut Node<MenuItems<'a, C, T, X>>> {
+        for child in node.iter_mut() {
+            let child = unpin(child);
+            if child.data().id() == id {
+                if *skip == 0 {
+                    return Some(child);
+                }
+                *skip -= 1;
+            }
+            if let Some(found) = Self::nth_child_mut(child, id, skip) {
+                return Some(found);
+            }
+        }
+        None
+    }
+
+    /// How many nodes below `node` have the given id
+    fn count_matching(node: &Node<MenuItems<'a, C, T, X>>, id: T) -> usize {
+        node.iter()
+            .map(|child| usize::from(child.data().id() == id) + Self::count_matching(child, id))
+            .sum()
+    }
+
+    /// Take every node below `node` with the given id out of the tree, keeping their order
+    fn detach_all(
+        node: &mut Node<MenuItems<'a, C, T, X>>,
+        id: T,
+    ) -> Forest<MenuItems<'a, C, T, X>> {
+        let mut detached = Forest::new();
+        while let Some(found) = Self::find_child_mut(node, id) {
+            detached.push_back(found.detach());
+        }
+        detached
+    }
+}
+
+impl<'a, C, T, X> Drawable for Menu<'a, C, T, X>
+where
+    C: PixelColor,
+    T: Copy + Clone + Sized,
+    X: CustomMenuItem<'a, C, T>,
+{
+    type Color = C;
+    type Output = ();
+
+    fn draw<D>(&self, display: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        let active_tree = self.get_active_submenu();
+        self.draw_menu(display, active_tree)?;
+
+        Ok(())
+    }
+}
+
+impl<'a, C, T, X> From<Menu<'a, C, T, X>> for Tree<MenuItems<'a, C, T, X>>
+where
+    C: PixelColor,
+    T: Copy + Clone + Sized,
+    X: CustomMenuItem<'a, C, T>,
+{
+    fn from(menu: Menu<'a, C, T, X>) -> Tree<MenuItems<'a, C, T, X>> {
+        menu.menu_tree_root
+    }
+}
+
+impl<'a, C, T, X> From<MenuItems<'a, C, T, X>> for Tree<MenuItems<'a, C, T, X>>
+where
+    C: PixelColor,
+    T: Copy + Clone + Sized,
+    X: CustomMenuItem<'a, C, T>,
+{
+    fn from(item: MenuItems<'a, C, T, X>) -> Tree<MenuItems<'a, C, T, X>> {
+        Tree::new(item)
+    }
+}
 
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub struct MenuStyle<'a, C> {
@@ -327,29 +1917,507 @@ pub struct MenuStyle<'a, C> {
     pub(crate) highlight_item_color: C,
     pub(crate) highlight_text_style: MonoTextStyle<'a, C>,
     pub(crate) highlight_indicator_fill_color: C,
+    pub(crate) highlight_style: HighlightStyle,
+    pub(crate) header_background_color: Option<C>,
+    pub(crate) separator_color: Option<C>,
+    pub(crate) disabled_character_style: MonoTextStyle<'a, C>,
+    pub(crate) value_character_style: MonoTextStyle<'a, C>,
+    pub(crate) description_character_style: MonoTextStyle<'a, C>,
+    pub(crate) header_style: HeaderStyle,
+    pub(crate) show_footer: bool,
+    pub(crate) help_panel_lines: u32,
+    pub(crate) checkbox_style: CheckboxStyle,
+}
+
+impl<'a, C> MenuStyle<'a, C>
+where
+    C: PixelColor,
+{
+    pub fn new(
+        menu_background_color: C,
+        heading_character_style: MonoTextStyle<'a, C>,
+        item_character_style: MonoTextStyle<'a, C>,
+        indicator_fill_color: C,
+        highlight_item_color: C,
+        highlight_text_style: MonoTextStyle<'a, C>,
+        highlight_indicator_fill_color: C,
+    ) -> Self {
+        Self {
+            menu_background_color,
+            heading_character_style,
+            item_character_style,
+            indicator_fill_color,
+            highlight_item_color,
+            highlight_text_style,
+            highlight_indicator_fill_color,
+            highlight_style: HighlightStyle::FilledBar,
+            header_background_color: None,
+            separator_color: None,
+            disabled_character_style: item_character_style,
+            value_character_style: item_character_style,
+            description_character_style: item_character_style,
+            header_style: HeaderStyle::default(),
+            show_footer: false,
+            help_panel_lines: 0,
+            checkbox_style: CheckboxStyle::Ascii,
+        }
+    }
+
+    /// Change how the highlighted item is marked, a filled bar by default
+    pub fn with_highlight_style(mut self, highlight_style: HighlightStyle) -> Self {
+        self.highlight_style = highlight_style;
+        self
+    }
+
+    /// Change the header alignment, breadcrumbs and underline
+    pub fn with_header_style(mut self, header_style: HeaderStyle) -> Self {
+        self.header_style = header_style;
+        self
+    }
+
+    /// Change the text style of item descriptions, the item style by default
+    pub fn with_description_style(
+        mut self,
+        description_character_style: MonoTextStyle<'a, C>,
+    ) -> Self {
+        self.description_character_style = description_character_style;
+        self
+    }
+
+    /// Show a footer of soft key hints for the highlighted item below the menu items
+    pub fn with_footer(mut self, show_footer: bool) -> Self {
+        self.show_footer = show_footer;
+        self
+    }
+
+    /// Change how checkbox items draw their state, "[X]" and "[ ]" by default
+    pub fn with_checkbox_style(mut self, checkbox_style: CheckboxStyle) -> Self {
+        self.checkbox_style = checkbox_style;
+        self
+    }
+
+    /// Reserve a panel of `lines` description-style lines below the items showing the help text of
+    /// the highlighted item. Zero lines, the default, leaves the panel out.
+    pub fn with_help_panel(mut self, lines: u32) -> Self {
+        self.help_panel_lines = lines;
+        self
+    }
+
+    /// Height of the help panel, or zero if it isn't shown
+    pub(crate) fn help_panel_height(&self) -> u32 {
+        match self.help_panel_lines {
+            0 => 0,
+            // One pixel for the separator line above the text
+            lines => lines * self.description_character_style.line_height() + 1,
+        }
+    }
+
+    /// Height of the footer, or zero if it isn't shown
+    pub(crate) fn footer_height(&self) -> u32 {
+        match self.show_footer {
+            // One pixel for the separator line above the hints
+            true => self.item_character_style.line_height() + 1,
+            false => 0,
+        }
+    }
+
+    /// Apply the changes of a per-item style override, if there is one
+    pub(crate) fn with_override(mut self, style_override: Option<StyleOverride<'a, C>>) -> Self {
+        let Some(style_override) = style_override else {
+            return self;
+        };
+        if let Some(font) = style_override.font {
+            self.item_character_style.font = font;
+            self.highlight_text_style.font = font;
+            self.disabled_character_style.font = font;
+            self.value_character_style.font = font;
+        }
+        if let Some(text_color) = style_override.text_color {
+            self.item_character_style.text_color = Some(text_color);
+            self.value_character_style.text_color = Some(text_color);
+            self.indicator_fill_color = text_color;
+        }
+        if let Some(highlight_color) = style_override.highlight_color {
+            self.highlight_item_color = highlight_color;
+        }
+        self
+    }
+
+    /// Draw the highlight behind an item at `position` spanning the width of the display
+    pub(crate) fn draw_highlight<D>(
+        &self,
+        display: &mut D,
+        position: Point,
+        item_height: u32,
+    ) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let highlight_area = Rectangle::new(
+            position,
+            Size::new(
+                display.bounding_box().size().width,
+                self.highlight_text_style.line_height().max(item_height),
+            ),
+        );
+        self.highlight_style
+            .draw_highlight(display, highlight_area, self)
+    }
+
+    /// Position of item content, leaving room for the highlight renderer if it needs it
+    pub(crate) fn content_position(&self, position: Point) -> Point {
+        position + Point::new(self.highlight_style.content_offset(self) as i32, 0)
+    }
+
+    /// Whether highlighted content sits on a filled highlight and must use highlight colours
+    pub(crate) fn inverts_content(&self, highlighted: bool) -> bool {
+        highlighted && HighlightRenderer::<C>::inverts_content(&self.highlight_style)
+    }
+
+    /// Text style for item labels, on the highlight or off it
+    pub fn content_text_style(&self, highlighted: bool) -> MonoTextStyle<'a, C> {
+        match self.inverts_content(highlighted) {
+            true => self.highlight_text_style,
+            false => self.item_character_style,
+        }
+    }
+
+    /// Text style for the value shown on the right of an item, such as a checkbox state
+    pub fn content_value_style(&self, highlighted: bool) -> MonoTextStyle<'a, C> {
+        match self.inverts_content(highlighted) {
+            true => self.highlight_text_style,
+            false => self.value_character_style,
+        }
+    }
+
+    /// Text style for the description beneath an item label
+    pub fn content_description_style(&self, highlighted: bool) -> MonoTextStyle<'a, C> {
+        match self.inverts_content(highlighted) {
+            true => {
+                let mut description_style = self.highlight_text_style;
+                description_style.font = self.description_character_style.font;
+                description_style
+            }
+            false => self.description_character_style,
+        }
+    }
+
+    /// Colour behind the item content
+    pub fn content_background_color(&self, highlighted: bool) -> C {
+        match self.inverts_content(highlighted) {
+            true => self.highlight_item_color,
+            false => self.menu_background_color,
+        }
+    }
+
+    /// Colour of shapes drawn in an item, such as the submenu arrow
+    pub fn content_indicator_color(&self, highlighted: bool) -> C {
+        match self.inverts_content(highlighted) {
+            true => self.highlight_indicator_fill_color,
+            false => self.indicator_fill_color,
+        }
+    }
 }
 
-impl<'a, C> MenuStyle<'a, C>
+/// Changes to the menu wide [`MenuStyle`] for a single item, such as a red "Factory reset" entry.
+/// Anything left as `None` falls back to the menu style.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct StyleOverride<'a, C> {
+    /// Colour of the label, value and indicator when not highlighted
+    pub text_color: Option<C>,
+    pub font: Option<&'a MonoFont<'a>>,
+    /// Colour of the highlight drawn behind the item
+    pub highlight_color: Option<C>,
+}
+
+/// Builds a [`MenuStyle`] from a pair of fonts and a [`MenuTheme`] of colours, with each colour
+/// adjustable afterwards
+#[derive(Debug, Clone, Copy)]
+pub struct MenuStyleBuilder<'a, C> {
+    heading_font: &'a MonoFont<'a>,
+    item_font: &'a MonoFont<'a>,
+    description_font: Option<&'a MonoFont<'a>>,
+    theme: MenuTheme<C>,
+    highlight_style: HighlightStyle,
+    header_style: Option<HeaderStyle>,
+    show_footer: bool,
+    help_panel_lines: u32,
+    checkbox_style: CheckboxStyle,
+}
+
+impl<'a, C> MenuStyleBuilder<'a, C>
 where
     C: PixelColor,
 {
     pub fn new(
-        menu_background_color: C,
-        heading_character_style: MonoTextStyle<'a, C>,
-        item_character_style: MonoTextStyle<'a, C>,
-        indicator_fill_color: C,
-        highlight_item_color: C,
-        highlight_text_style: MonoTextStyle<'a, C>,
-        highlight_indicator_fill_color: C,
+        heading_font: &'a MonoFont<'a>,
+        item_font: &'a MonoFont<'a>,
+        theme: MenuTheme<C>,
     ) -> Self {
         Self {
-            menu_background_color,
-            heading_character_style,
+            heading_font,
+            item_font,
+            description_font: None,
+            theme,
+            highlight_style: HighlightStyle::FilledBar,
+            header_style: None,
+            show_footer: false,
+            help_panel_lines: 0,
+            checkbox_style: CheckboxStyle::Ascii,
+        }
+    }
+
+    pub fn heading_font(mut self, heading_font: &'a MonoFont<'a>) -> Self {
+        self.heading_font = heading_font;
+        self
+    }
+
+    pub fn item_font(mut self, item_font: &'a MonoFont<'a>) -> Self {
+        self.item_font = item_font;
+        self
+    }
+
+    /// Font of item descriptions, drawn in the theme's disabled colour. Defaults to the item font.
+    pub fn description_font(mut self, description_font: &'a MonoFont<'a>) -> Self {
+        self.description_font = Some(description_font);
+        self
+    }
+
+    pub fn theme(mut self, theme: MenuTheme<C>) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    pub fn highlight_style(mut self, highlight_style: HighlightStyle) -> Self {
+        self.highlight_style = highlight_style;
+        self
+    }
+
+    /// Header layout. Defaults to a left aligned title, underlined if the theme has a separator
+    /// colour.
+    pub fn header_style(mut self, header_style: HeaderStyle) -> Self {
+        self.header_style = Some(header_style);
+        self
+    }
+
+    /// Show a footer of soft key hints for the highlighted item
+    pub fn footer(mut self, show_footer: bool) -> Self {
+        self.show_footer = show_footer;
+        self
+    }
+
+    pub fn help_panel(mut self, lines: u32) -> Self {
+        self.help_panel_lines = lines;
+        self
+    }
+
+    pub fn checkbox_style(mut self, checkbox_style: CheckboxStyle) -> Self {
+        self.checkbox_style = checkbox_style;
+        self
+    }
+
+    pub fn background_color(mut self, color: C) -> Self {
+        self.theme.background = color;
+        self
+    }
+
+    pub fn heading_color(mut self, color: C) -> Self {
+        self.theme.heading = color;
+        self
+    }
+
+    /// Fill behind the heading, or `None` to leave it on the menu background
+    pub fn header_background_color(mut self, color: Option<C>) -> Self {
+        self.theme.header_background = color;
+        self
+    }
+
+    /// Colour of the lines under the heading and either side of section labels, or `None` to
+    /// draw no separators
+    pub fn separator_color(mut self, color: Option<C>) -> Self {
+        self.theme.separator = color;
+        self
+    }
+
+    pub fn item_color(mut self, color: C) -> Self {
+        self.theme.item = color;
+        self
+    }
+
+    pub fn value_color(mut self, color: C) -> Self {
+        self.theme.value = color;
+        self
+    }
+
+    pub fn disabled_color(mut self, color: C) -> Self {
+        self.theme.disabled = color;
+        self
+    }
+
+    pub fn indicator_color(mut self, color: C) -> Self {
+        self.theme.indicator = color;
+        self
+    }
+
+    pub fn highlight_color(mut self, color: C) -> Self {
+        self.theme.highlight = color;
+        self
+    }
+
+    pub fn highlight_text_color(mut self, color: C) -> Self {
+        self.theme.highlight_text = color;
+        self
+    }
+
+    pub fn highlight_indicator_color(mut self, color: C) -> Self {
+        self.theme.highlight_indicator = color;
+        self
+    }
+
+    pub fn build(self) -> MenuStyle<'a, C> {
+        let theme = self.theme;
+        let item_character_style = MonoTextStyle::new(self.item_font, theme.item);
+        MenuStyle {
+            menu_background_color: theme.background,
+            heading_character_style: MonoTextStyle::new(self.heading_font, theme.heading),
             item_character_style,
-            indicator_fill_color,
-            highlight_item_color,
-            highlight_text_style,
-            highlight_indicator_fill_color,
+            indicator_fill_color: theme.indicator,
+            highlight_item_color: theme.highlight,
+            highlight_text_style: MonoTextStyle::new(self.item_font, theme.highlight_text),
+            highlight_indicator_fill_color: theme.highlight_indicator,
+            highlight_style: self.highlight_style,
+            header_background_color: theme.header_background,
+            separator_color: theme.separator,
+            disabled_character_style: MonoTextStyle::new(self.item_font, theme.disabled),
+            value_character_style: MonoTextStyle::new(self.item_font, theme.value),
+            description_character_style: MonoTextStyle::new(
+                self.description_font.unwrap_or(self.item_font),
+                theme.disabled,
+            ),
+            header_style: self.header_style.unwrap_or(HeaderStyle {
+                underline: theme.separator.is_some(),
+                ..HeaderStyle::default()
+            }),
+            show_footer: self.show_footer,
+            help_panel_lines: self.help_panel_lines,
+            checkbox_style: self.checkbox_style,
+        }
+    }
+}
+
+/// Parts of the display the menu is drawn in, top to bottom
+struct MenuAreas {
+    header: Rectangle,
+    items: Rectangle,
+    help_panel: Rectangle,
+    footer: Rectangle,
+}
+
+/// Node of a menu tree holding one item
+type ItemNode<'a, C, T, X> = Node<MenuItems<'a, C, T, X>>;
+
+/// Root of the menu structure and, if a submenu is shown, of its copy
+type TreeRoots<'r, 'a, C, T, X> = (
+    &'r mut ItemNode<'a, C, T, X>,
+    Option<&'r mut ItemNode<'a, C, T, X>>,
+);
+
+/// Unpin a node of a menu tree to change it in place.
+///
+/// I seem to be missing something potentially? Behaviour doesn't seem to match the tree crate
+/// examples, but they use simple types. In any case the menu never moves the memory of a node, only
+/// relinks or drops nodes, and it remains valid so doesn't violate the Pin invariants.
+fn unpin<N>(node: Pin<&mut Node<N>>) -> &mut Node<N> {
+    unsafe { Pin::into_inner_unchecked(node) }
+}
+
+/// Deepest nesting of submenus that can be navigated into. Selecting a submenu below this reports
+/// [`SelectedData::TooDeep`] instead of opening it.
+pub const MAX_MENU_DEPTH: usize = 8;
+
+/// Index of the submenu taken at each level on the way from the root to the active submenu
+#[derive(Clone, Copy)]
+struct NavigationPath {
+    indices: [usize; MAX_MENU_DEPTH],
+    depth: usize,
+}
+
+impl NavigationPath {
+    pub fn new() -> Self {
+        Self {
+            indices: [0; MAX_MENU_DEPTH],
+            depth: 0,
+        }
+    }
+
+    /// Returns false if the path is already at the maximum depth
+    pub fn push(&mut self, index: usize) -> bool {
+        if self.depth >= MAX_MENU_DEPTH {
+            return false;
+        }
+        self.indices[self.depth] = index;
+        self.depth += 1;
+        true
+    }
+
+    /// Returns false if the path is already at the maximum depth
+    pub fn push_front(&mut self, index: usize) -> bool {
+        if self.depth >= MAX_MENU_DEPTH {
+            return false;
+        }
+        self.indices.copy_within(..self.depth, 1);
+        self.indices[0] = index;
+        self.depth += 1;
+        true
+    }
+
+    pub fn pop(&mut self) -> Option<usize> {
+        if self.depth == 0 {
+            return None;
+        }
+        self.depth -= 1;
+        Some(self.indices[self.depth])
+    }
+
+    pub fn clear(&mut self) {
+        self.depth = 0;
+    }
+
+    pub fn indices(&self) -> &[usize] {
+        &self.indices[..self.depth]
+    }
+}
+
+/// Active menu and highlighted item of a [`Menu`] by id, see [`Menu::restore_position`]
+struct MenuPosition<T> {
+    /// Id of the submenu taken at each level, as in [`NavigationPath`]
+    path: [Option<T>; MAX_MENU_DEPTH],
+    /// Id of the highlighted item and how many rows above it in the menu share that id, which
+    /// tells the rows of a radio group apart
+    highlighted: Option<(T, usize)>,
+}
+
+/// Which side of an existing item a new one goes
+#[derive(Clone, Copy)]
+enum Placement {
+    Before,
+    After,
+}
+
+impl Placement {
+    /// Put `items` next to `target`, in their order
+    fn insert<T>(self, target: &mut Node<T>, mut items: Forest<T>) {
+        match self {
+            Placement::Before => {
+                while let Some(item) = items.pop_front() {
+                    target.insert_prev_sib(item);
+                }
+            }
+            Placement::After => {
+                while let Some(item) = items.pop_back() {
+                    target.insert_next_sib(item);
+                }
+            }
         }
     }
 }
@@ -368,6 +2436,7 @@ impl MenuState {
     }
     pub fn update_item_count(&mut self, item_count: usize) {
         self.item_count = item_count;
+        self.highlighted_item = self.highlighted_item.min(item_count.saturating_sub(1));
     }
     pub fn move_down(&mut self) {
         self.highlighted_item += 1;
@@ -378,12 +2447,38 @@ impl MenuState {
 
     pub fn move_up(&mut self) {
         if self.highlighted_item == 0 {
-            self.highlighted_item = self.item_count - 1;
+            self.highlighted_item = self.item_count.saturating_sub(1);
         } else {
             self.highlighted_item -= 1;
         }
     }
 
+    /// Move down by `step` items, wrapping to the top of the same column of a grid `step` wide
+    pub fn move_down_by(&mut self, step: usize) {
+        let step = step.max(1);
+        self.highlighted_item += step;
+        if self.highlighted_item >= self.item_count {
+            self.highlighted_item %= step;
+        }
+    }
+
+    /// Move up by `step` items, wrapping to the bottom of the same column of a grid `step` wide
+    pub fn move_up_by(&mut self, step: usize) {
+        let step = step.max(1);
+        if self.highlighted_item >= step {
+            self.highlighted_item -= step;
+        } else if self.item_count > 0 {
+            let last_item = self.item_count - 1;
+            let column = self.highlighted_item % step;
+            self.highlighted_item = last_item - (last_item % step + step - column) % step;
+        }
+    }
+
+    /// Highlight the item at `index`, kept within the items by the next item count update
+    pub fn set_highlighted_item(&mut self, index: usize) {
+        self.highlighted_item = index;
+    }
+
     pub fn highlighted_item(&self) -> usize {
         self.highlighted_item
     }
@@ -392,3 +2487,619 @@ impl MenuState {
         self.item_count
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    use embedded_graphics::mock_display::MockDisplay;
+    use embedded_graphics::mono_font::ascii::FONT_6X10;
+    use embedded_graphics::pixelcolor::BinaryColor;
+    use theme::MenuTheme;
+
+    fn style() -> MenuStyle<'static, BinaryColor> {
+        MenuStyleBuilder::new(&FONT_6X10, &FONT_6X10, MenuTheme::dark()).build()
+    }
+
+    #[test]
+    fn help_page_stops_left_and_right_editing() {
+        let mut menu = Menu::new("Menu", 0, style());
+        menu.add_slider("Volume", 1, 0, 10, 5);
+        menu.set_help_text(1, Some("How loud"));
+        assert_eq!(menu.select_item(), None);
+        assert!(menu.show_help());
+        menu.navigate_right();
+        menu.navigate_left();
+        menu.navigate_right();
+        menu.close_help();
+        assert_eq!(
+            menu.select_item(),
+            Some(SelectedData::Slider { id: 1, value: 5 })
+        );
+    }
+
+    fn mock_display() -> MockDisplay<BinaryColor> {
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        display.set_allow_out_of_bounds_drawing(true);
+        display
+    }
+
+    #[test]
+    fn epaper_draw_always_draws_everything() {
+        let mut menu = Menu::new("Menu", 0, style());
+        menu.add_action("Action", 1);
+        menu.set_render_mode(RenderMode::EPaper {
+            full_refresh_interval: 0,
+        });
+        menu.draw(&mut mock_display()).unwrap();
+        menu.draw_changes(&mut mock_display()).unwrap();
+        assert!(!menu.needs_redraw());
+
+        let mut display = mock_display();
+        menu.draw(&mut display).unwrap();
+        assert_eq!(display.affected_area(), display.bounding_box());
+        let report = menu.draw_changes(&mut mock_display()).unwrap();
+        assert!(report.is_empty());
+    }
+
+    #[test]
+    fn submenus_past_the_depth_limit_report_too_deep() {
+        let mut menu = Menu::new("Menu", 0, style());
+        let mut submenu = Menu::new("Level", MAX_MENU_DEPTH as u32 + 1, style());
+        submenu.add_action("Action", 100);
+        for id in (1..=MAX_MENU_DEPTH as u32).rev() {
+            let mut parent = Menu::new("Level", id, style());
+            parent.add_submenu(submenu);
+            submenu = parent;
+        }
+        menu.add_submenu(submenu);
+
+        for id in 1..=MAX_MENU_DEPTH as u32 {
+            assert_eq!(menu.select_item(), Some(SelectedData::Submenu { id }));
+        }
+        let id = MAX_MENU_DEPTH as u32 + 1;
+        assert_eq!(menu.select_item(), Some(SelectedData::TooDeep { id }));
+        // Still in the deepest menu that opened
+        assert_eq!(menu.select_item(), Some(SelectedData::TooDeep { id }));
+    }
+
+    fn radio_choices(menu: &Menu<'_, BinaryColor, u32>) -> [bool; 6] {
+        let mut choices = [false; 6];
+        for (choice, item) in choices.iter_mut().zip(menu.get_active_submenu().iter()) {
+            if let MenuItems::Radio(radio) = item.data() {
+                *choice = radio.is_chosen();
+            }
+        }
+        choices
+    }
+
+    #[test]
+    fn radio_groups_sharing_options_stay_apart() {
+        const LEVELS: &[&str] = &["Low", "Mid", "High"];
+        let mut menu = Menu::new("Menu", 0, style());
+        menu.add_radio_group(1, LEVELS, 0);
+        menu.add_radio_group(2, LEVELS, 0);
+        assert_eq!(
+            radio_choices(&menu),
+            [true, false, false, true, false, false]
+        );
+
+        menu.navigate_down();
+        menu.navigate_down();
+        assert_eq!(
+            menu.select_item(),
+            Some(SelectedData::MultiOption {
+                id: 1,
+                option_id: 2
+            })
+        );
+        assert_eq!(
+            radio_choices(&menu),
+            [false, false, true, true, false, false]
+        );
+
+        menu.navigate_down();
+        menu.navigate_down();
+        menu.select_item();
+        assert_eq!(
+            radio_choices(&menu),
+            [false, false, true, false, true, false]
+        );
+    }
+
+    #[test]
+    fn setting_by_id_reaches_every_radio_row() {
+        let mut menu = Menu::new("Menu", 0, style());
+        menu.add_radio_group(1, &["Low", "High"], 0);
+        menu.set_help_text(1, Some("Level"));
+        assert!(menu
+            .get_active_submenu()
+            .iter()
+            .all(|item| item.data().help_text() == Some("Level")));
+    }
+
+    fn shown_ids(menu: &Menu<'_, BinaryColor, u32>) -> [u32; 8] {
+        let mut ids = [0; 8];
+        for (id, item) in ids.iter_mut().zip(menu.get_active_submenu().iter()) {
+            *id = item.data().id();
+        }
+        ids
+    }
+
+    fn action(id: u32) -> MenuItems<'static, BinaryColor, u32> {
+        MenuItems::Action(ActionItem::new("Action", id, style()))
+    }
+
+    /// Actions 1 and 3 around radio group 2, with its last row "High" highlighted
+    fn menu_with_radio_group() -> Menu<'static, BinaryColor, u32> {
+        let mut menu = Menu::new("Menu", 0, style());
+        menu.add_action("First", 1);
+        menu.add_radio_group(2, &["Low", "Mid", "High"], 0);
+        menu.add_action("Last", 3);
+        for _ in 0..3 {
+            menu.navigate_down();
+        }
+        menu
+    }
+
+    fn high_chosen() -> Option<SelectedData<u32>> {
+        Some(SelectedData::MultiOption {
+            id: 2,
+            option_id: 2,
+        })
+    }
+
+    #[test]
+    fn inserting_next_to_a_radio_group_keeps_it_whole() {
+        let mut menu = menu_with_radio_group();
+        assert!(menu.insert_after(2, action(4)));
+        assert!(menu.insert_before(2, action(5)));
+        assert_eq!(shown_ids(&menu), [1, 5, 2, 2, 2, 4, 3, 0]);
+        assert_eq!(menu.select_item(), high_chosen());
+    }
+
+    #[test]
+    fn removing_a_radio_group_removes_every_row() {
+        let mut menu = menu_with_radio_group();
+        assert!(menu.remove_item(2));
+        assert_eq!(shown_ids(&menu), [1, 3, 0, 0, 0, 0, 0, 0]);
+        // The highlight stays on the same row, moved up to the end of the shorter menu
+        assert_eq!(menu.select_item(), Some(SelectedData::Action { id: 3 }));
+        assert!(!menu.remove_item(2));
+    }
+
+    #[test]
+    fn moving_a_radio_group_moves_every_row() {
+        let mut menu = menu_with_radio_group();
+        assert!(menu.move_after(2, 3));
+        assert_eq!(shown_ids(&menu), [1, 3, 2, 2, 2, 0, 0, 0]);
+        assert!(menu.move_before(1, 2));
+        assert!(menu.move_after(3, 2));
+        assert_eq!(shown_ids(&menu), [1, 2, 2, 2, 3, 0, 0, 0]);
+        assert!(menu.move_before(2, 1));
+        assert_eq!(shown_ids(&menu), [2, 2, 2, 1, 3, 0, 0, 0]);
+        assert_eq!(menu.select_item(), high_chosen());
+        assert!(!menu.move_after(2, 2));
+        assert!(!menu.move_after(2, 9));
+    }
+
+    /// Top level actions 1 and 3 around submenu 10 with actions 11 and 12, showing the submenu
+    /// with 12 highlighted
+    fn menu_inside_submenu() -> Menu<'static, BinaryColor, u32> {
+        let mut menu = Menu::new("Menu", 0, style());
+        let mut submenu = Menu::new("Submenu", 10, style());
+        submenu.add_action("Eleven", 11);
+        submenu.add_action("Twelve", 12);
+        menu.add_action("First", 1);
+        menu.add_submenu(submenu);
+        menu.add_action("Last", 3);
+        menu.navigate_down();
+        assert_eq!(menu.select_item(), Some(SelectedData::Submenu { id: 10 }));
+        menu.navigate_down();
+        menu
+    }
+
+    #[test]
+    fn changes_around_the_shown_submenu_keep_it_shown() {
+        let mut menu = menu_inside_submenu();
+        assert!(menu.insert_before(10, action(5)));
+        assert!(menu.move_after(10, 3));
+        assert!(menu.insert_after(11, action(13)));
+        assert_eq!(menu.select_item(), Some(SelectedData::Action { id: 12 }));
+        assert_eq!(shown_ids(&menu), [11, 13, 12, 0, 0, 0, 0, 0]);
+
+        menu.navigate_back();
+        assert_eq!(shown_ids(&menu), [1, 5, 3, 10, 0, 0, 0, 0]);
+        for _ in 0..3 {
+            menu.navigate_down();
+        }
+        assert_eq!(menu.select_item(), Some(SelectedData::Submenu { id: 10 }));
+        assert_eq!(shown_ids(&menu), [11, 13, 12, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn moving_items_of_the_shown_submenu_moves_them_in_both_copies() {
+        let mut menu = menu_inside_submenu();
+        assert!(menu.move_before(12, 11));
+        assert_eq!(shown_ids(&menu), [12, 11, 0, 0, 0, 0, 0, 0]);
+        // The highlight follows the moved item
+        assert_eq!(menu.select_item(), Some(SelectedData::Action { id: 12 }));
+
+        menu.navigate_back();
+        menu.navigate_down();
+        assert_eq!(menu.select_item(), Some(SelectedData::Submenu { id: 10 }));
+        assert_eq!(shown_ids(&menu), [12, 11, 0, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn removing_the_shown_submenu_goes_back_up() {
+        let mut menu = menu_inside_submenu();
+        assert!(menu.clear_submenu(10));
+        assert_eq!(shown_ids(&menu), [0; 8]);
+        assert!(menu.remove_item(10));
+        assert_eq!(shown_ids(&menu), [1, 3, 0, 0, 0, 0, 0, 0]);
+        assert!(!menu.clear_submenu(10));
+    }
+
+    #[test]
+    fn tall_rows_scroll_the_highlighted_row_into_view() {
+        let mut menu = Menu::new("Menu", 0, style());
+        for id in 1..=6 {
+            menu.add_action("Action", id);
+            menu.set_description(id, Some("What it does"));
+        }
+        let item_area = menu
+            .menu_areas(Rectangle::new(Point::zero(), Size::new(128, 48)))
+            .items;
+        let row_height = menu
+            .get_active_submenu()
+            .root()
+            .front()
+            .unwrap()
+            .data()
+            .height_in(128);
+        // Room for one row with its description, but not two
+        assert!(row_height <= item_area.size.height && 2 * row_height > item_area.size.height);
+
+        for highlighted_item in 0..6 {
+            assert_eq!(menu.menu_state.highlighted_item(), highlighted_item);
+            // The highlighted row is the first one drawn, as nothing else fits with it
+            assert_eq!(
+                menu.list_scroll_offset(highlighted_item, item_area),
+                highlighted_item
+            );
+            menu.navigate_down();
+        }
+    }
+
+    /// Menu state for a grid three wide with two full rows and two items on the last
+    fn grid_state(highlighted_item: usize) -> MenuState {
+        let mut state = MenuState::new();
+        state.update_item_count(8);
+        state.set_highlighted_item(highlighted_item);
+        state
+    }
+
+    #[test]
+    fn moving_down_a_grid_wraps_to_the_top_of_the_column() {
+        let mut state = grid_state(1);
+        state.move_down_by(3);
+        assert_eq!(state.highlighted_item(), 4);
+        state.move_down_by(3);
+        assert_eq!(state.highlighted_item(), 7);
+        state.move_down_by(3);
+        assert_eq!(state.highlighted_item(), 1);
+
+        // The last row is short, so the third column wraps from its middle row
+        let mut state = grid_state(5);
+        state.move_down_by(3);
+        assert_eq!(state.highlighted_item(), 2);
+    }
+
+    #[test]
+    fn moving_up_a_grid_wraps_to_the_bottom_of_the_column() {
+        for (column, bottom) in [(0, 6), (1, 7), (2, 5)] {
+            let mut state = grid_state(column);
+            state.move_up_by(3);
+            assert_eq!(state.highlighted_item(), bottom);
+            state.move_up_by(3);
+            assert_eq!(state.highlighted_item(), bottom - 3);
+        }
+    }
+
+    #[test]
+    fn grid_steps_stay_in_empty_and_single_column_menus() {
+        let mut state = MenuState::new();
+        state.move_up_by(3);
+        state.move_down_by(3);
+        assert_eq!(state.highlighted_item(), 0);
+
+        // A step of zero moves like a list
+        let mut state = grid_state(0);
+        state.move_up_by(0);
+        assert_eq!(state.highlighted_item(), 7);
+        state.move_down_by(0);
+        assert_eq!(state.highlighted_item(), 0);
+    }
+
+    /// Whether everything drawn on `display` lies inside `viewport`
+    fn drawn_inside(display: &MockDisplay<BinaryColor>, viewport: Rectangle) -> bool {
+        let drawn = display.affected_area();
+        drawn.is_zero_sized() || viewport.intersection(&drawn) == drawn
+    }
+
+    #[test]
+    fn nothing_is_drawn_outside_the_viewport() {
+        let mut menu = Menu::new("A menu title too long for the viewport", 0, style());
+        menu.add_action("An action label too long for the viewport", 1);
+        menu.add_checkbox("A checkbox label too long for the viewport", 2, true);
+        let viewport = Rectangle::new(Point::new(8, 8), Size::new(32, 40));
+        menu.set_viewport(Some(viewport));
+
+        let mut display = mock_display();
+        menu.draw(&mut display).unwrap();
+        assert!(drawn_inside(&display, viewport));
+
+        // Only the rows the highlight moved between are redrawn this time
+        menu.draw_changes(&mut display).unwrap();
+        menu.navigate_down();
+        let mut display = mock_display();
+        menu.draw_changes(&mut display).unwrap();
+        assert!(!display.affected_area().is_zero_sized());
+        assert!(drawn_inside(&display, viewport));
+    }
+
+    /// Submenu 10 holding `item`, entered from the top level menu with `item` highlighted
+    fn entered_submenu_with(
+        item: MenuItems<'static, BinaryColor, u32>,
+    ) -> Menu<'static, BinaryColor, u32> {
+        let mut menu = Menu::new("Menu", 0, style());
+        let mut submenu = Menu::new("Submenu", 10, style());
+        submenu.add_item(item);
+        menu.add_submenu(submenu);
+        assert_eq!(menu.select_item(), Some(SelectedData::Submenu { id: 10 }));
+        menu
+    }
+
+    /// Leave the shown submenu and enter it again, highlighting the first item of both
+    fn reenter_submenu(menu: &mut Menu<'static, BinaryColor, u32>) {
+        menu.navigate_back();
+        assert_eq!(menu.select_item(), Some(SelectedData::Submenu { id: 10 }));
+    }
+
+    #[test]
+    fn slider_edits_outlast_leaving_the_submenu() {
+        let slider = SliderItem::new("Volume", 11, style(), 0, 10, 5);
+        let mut menu = entered_submenu_with(MenuItems::Slider(slider));
+        assert_eq!(menu.select_item(), None);
+        menu.navigate_up();
+        menu.navigate_up();
+        assert_eq!(
+            menu.select_item(),
+            Some(SelectedData::Slider { id: 11, value: 7 })
+        );
+
+        reenter_submenu(&mut menu);
+        assert_eq!(menu.select_item(), None);
+        assert_eq!(
+            menu.select_item(),
+            Some(SelectedData::Slider { id: 11, value: 7 })
+        );
+    }
+
+    #[test]
+    fn checkbox_toggles_outlast_leaving_the_submenu() {
+        let checkbox = CheckboxItem::new("Wi-Fi", 11, style(), false);
+        let mut menu = entered_submenu_with(MenuItems::Checkbox(checkbox));
+        assert_eq!(
+            menu.select_item(),
+            Some(SelectedData::Checkbox {
+                id: 11,
+                state: true
+            })
+        );
+
+        reenter_submenu(&mut menu);
+        assert_eq!(
+            menu.select_item(),
+            Some(SelectedData::Checkbox {
+                id: 11,
+                state: false
+            })
+        );
+    }
+
+    #[test]
+    fn radio_choices_outlast_leaving_the_submenu() {
+        let mut menu = Menu::new("Menu", 0, style());
+        let mut submenu = Menu::new("Submenu", 10, style());
+        submenu.add_radio_group(11, &["Low", "Mid", "High"], 0);
+        menu.add_submenu(submenu);
+        assert_eq!(menu.select_item(), Some(SelectedData::Submenu { id: 10 }));
+        menu.navigate_down();
+        menu.navigate_down();
+        menu.select_item();
+
+        reenter_submenu(&mut menu);
+        let stored = menu.menu_tree_root.iter().next().unwrap();
+        let chosen = stored.iter().map(|row| match row.data() {
+            MenuItems::Radio(radio) => radio.is_chosen(),
+            _ => panic!("not a radio row"),
+        });
+        assert!(chosen.eq([false, false, true]));
+        let shown = menu
+            .get_active_submenu()
+            .iter()
+            .map(|row| match row.data() {
+                MenuItems::Radio(radio) => radio.is_chosen(),
+                _ => panic!("not a radio row"),
+            });
+        assert!(shown.eq([false, false, true]));
+    }
+
+    #[test]
+    fn date_edits_outlast_leaving_the_submenu() {
+        let date = DateItem::new("Due", 11, style(), Date::new(2024, 2, 29).unwrap());
+        let mut menu = entered_submenu_with(MenuItems::Date(date));
+        menu.select_item();
+        menu.navigate_up();
+        menu.select_item();
+        menu.select_item();
+        let edited = Date::new(2025, 2, 28).unwrap();
+        assert_eq!(
+            menu.select_item(),
+            Some(SelectedData::Date {
+                id: 11,
+                date: edited
+            })
+        );
+
+        reenter_submenu(&mut menu);
+        let Some(MenuItems::Date(stored)) = Menu::find_item(menu.menu_tree_root.root(), 11) else {
+            panic!("no date item");
+        };
+        assert_eq!(stored.date(), edited);
+    }
+
+    #[test]
+    fn locks_are_only_set_on_submenus() {
+        let mut menu = menu_inside_submenu();
+        let lock = SubmenuLock::passcode(&[1, 2]);
+        assert!(!menu.set_submenu_lock(1, lock));
+        assert!(!menu.set_submenu_lock(99, lock));
+        assert!(menu.set_submenu_lock(10, lock));
+    }
+
+    /// Entries counted by [`numbered_actions`], as populate callbacks can't capture state
+    static POPULATE_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    /// One more action on every entry, numbered from 11
+    fn numbered_actions(menu: &mut Menu<'static, BinaryColor, u32>) {
+        let calls = POPULATE_CALLS.fetch_add(1, Ordering::Relaxed) + 1;
+        for id in 11..11 + calls as u32 {
+            menu.add_action("Network", id);
+        }
+    }
+
+    #[test]
+    fn populated_submenus_are_rebuilt_on_every_entry() {
+        let mut menu = Menu::new("Menu", 0, style());
+        let mut submenu = Menu::new("Submenu", 10, style());
+        submenu.add_action("Placeholder", 99);
+        submenu.set_populate(Some(&numbered_actions));
+        menu.add_submenu(submenu);
+
+        for entries in 1..=3 {
+            if entries == 1 {
+                assert_eq!(menu.select_item(), Some(SelectedData::Submenu { id: 10 }));
+            } else {
+                reenter_submenu(&mut menu);
+            }
+            assert_eq!(POPULATE_CALLS.load(Ordering::Relaxed), entries);
+            assert_eq!(menu.get_active_submenu().iter().count(), entries);
+            let stored = menu.menu_tree_root.iter().next().unwrap();
+            assert_eq!(stored.iter().count(), entries);
+            for _ in 1..entries {
+                menu.navigate_down();
+            }
+            assert_eq!(
+                menu.select_item(),
+                Some(SelectedData::Action {
+                    id: 10 + entries as u32
+                })
+            );
+        }
+    }
+
+    /// A switched off checkbox, built afresh on every entry
+    fn fresh_checkbox(menu: &mut Menu<'static, BinaryColor, u32>) {
+        menu.add_checkbox("Wi-Fi", 11, false);
+    }
+
+    #[test]
+    fn edits_to_populated_items_are_discarded_on_reentry() {
+        let mut menu = Menu::new("Menu", 0, style());
+        let mut submenu = Menu::new("Submenu", 10, style());
+        submenu.set_populate(Some(&fresh_checkbox));
+        menu.add_submenu(submenu);
+        assert_eq!(menu.select_item(), Some(SelectedData::Submenu { id: 10 }));
+        assert_eq!(
+            menu.select_item(),
+            Some(SelectedData::Checkbox {
+                id: 11,
+                state: true
+            })
+        );
+
+        reenter_submenu(&mut menu);
+        assert_eq!(
+            menu.select_item(),
+            Some(SelectedData::Checkbox {
+                id: 11,
+                state: true
+            })
+        );
+    }
+
+    #[test]
+    fn moving_the_highlight_redraws_only_the_two_rows() {
+        let mut menu = Menu::new("Menu", 0, style());
+        menu.add_action("First", 1);
+        menu.add_action("Second", 2);
+        menu.add_action("Third", 3);
+        let mut display = mock_display();
+        menu.draw_changes(&mut display).unwrap();
+        assert!(!menu.needs_redraw());
+
+        menu.navigate_down();
+        assert!(menu.needs_redraw());
+        let report = menu.draw_changes(&mut display).unwrap();
+        let row = |top: i32| Rectangle::new(Point::new(0, top), Size::new(64, 10));
+        assert_eq!(report.changed_regions(), &[row(10), row(20)]);
+        assert!(!menu.needs_redraw());
+    }
+
+    #[test]
+    fn starting_to_edit_redraws_the_footer() {
+        let mut menu = Menu::new("Menu", 0, style().with_footer(true));
+        menu.add_slider("Volume", 1, 0, 10, 5);
+        menu.add_action("Action", 2);
+        let mut display = mock_display();
+        menu.draw_changes(&mut display).unwrap();
+
+        assert_eq!(menu.select_item(), None);
+        let report = menu.draw_changes(&mut display).unwrap();
+        let footer_height = menu.menu_style.footer_height();
+        let footer = Rectangle::new(
+            Point::new(0, 64 - footer_height as i32),
+            Size::new(64, footer_height),
+        );
+        assert!(report.changed_regions().contains(&footer));
+        assert!(!report.changed_regions().contains(&display.bounding_box()));
+    }
+
+    /// Whether anything is drawn in the ten rows of pixels starting at `top`
+    fn rows_drawn(display: &MockDisplay<BinaryColor>, top: i32) -> bool {
+        (top..top + 10)
+            .flat_map(|y| (0..64).map(move |x| Point::new(x, y)))
+            .any(|point| display.get_pixel(point) == Some(BinaryColor::On))
+    }
+
+    #[test]
+    fn values_move_to_a_second_line_in_narrow_menus() {
+        let mut menu = Menu::new("Menu", 0, style());
+        menu.add_action("Next", 1);
+        menu.add_checkbox("Wi-Fi", 2, true);
+        let mut display = mock_display();
+        menu.draw(&mut display).unwrap();
+        assert!(rows_drawn(&display, 20));
+        assert!(!rows_drawn(&display, 30));
+
+        menu.set_viewport(Some(Rectangle::new(Point::zero(), Size::new(40, 64))));
+        let mut display = mock_display();
+        menu.draw(&mut display).unwrap();
+        assert!(rows_drawn(&display, 20));
+        assert!(rows_drawn(&display, 30));
+    }
+}
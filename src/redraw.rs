@@ -0,0 +1,207 @@
+use crate::rotation::Rotation;
+use embedded_graphics::prelude::Transform;
+use embedded_graphics::primitives::Rectangle;
+
+/// Most separate areas a [`DrawReport`] lists before merging the last ones together
+pub const MAX_CHANGED_REGIONS: usize = 6;
+
+/// Items with an index past this are tracked together rather than one by one
+const TRACKED_ITEMS: usize = u64::BITS as usize;
+
+/// Parts of the menu that changed since it was last drawn
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct DirtyRegions {
+    everything: bool,
+    header: bool,
+    help_panel: bool,
+    footer: bool,
+    all_items: bool,
+    /// One bit per item index of the active menu
+    items: u64,
+    /// Index of the first list item shown when the menu was last drawn
+    scroll_offset: usize,
+}
+
+impl DirtyRegions {
+    /// Nothing has been drawn yet so everything needs drawing
+    pub fn new() -> Self {
+        Self {
+            everything: true,
+            header: false,
+            help_panel: false,
+            footer: false,
+            all_items: false,
+            items: 0,
+            scroll_offset: 0,
+        }
+    }
+
+    /// Nothing changed since the menu was drawn with the list scrolled to `scroll_offset`
+    pub fn drawn(scroll_offset: usize) -> Self {
+        Self {
+            everything: false,
+            scroll_offset,
+            ..Self::new()
+        }
+    }
+
+    pub fn mark_everything(&mut self) {
+        self.everything = true;
+    }
+
+    pub fn mark_header(&mut self) {
+        self.header = true;
+    }
+
+    pub fn mark_help_panel(&mut self) {
+        self.help_panel = true;
+    }
+
+    pub fn mark_footer(&mut self) {
+        self.footer = true;
+    }
+
+    pub fn mark_item(&mut self, index: usize) {
+        if index < TRACKED_ITEMS {
+            self.items |= 1 << index;
+        } else {
+            self.all_items = true;
+        }
+    }
+
+    pub fn is_clean(&self) -> bool {
+        !self.everything && !self.header && !self.help_panel && !self.footer && !self.any_items()
+    }
+
+    pub fn everything(&self) -> bool {
+        self.everything
+    }
+
+    pub fn header(&self) -> bool {
+        self.header
+    }
+
+    pub fn help_panel(&self) -> bool {
+        self.help_panel
+    }
+
+    pub fn footer(&self) -> bool {
+        self.footer
+    }
+
+    pub fn any_items(&self) -> bool {
+        self.all_items || self.items != 0
+    }
+
+    pub fn all_items(&self) -> bool {
+        self.all_items
+    }
+
+    pub fn item(&self, index: usize) -> bool {
+        match index < TRACKED_ITEMS {
+            true => self.items & (1 << index) != 0,
+            false => self.all_items,
+        }
+    }
+
+    pub fn scroll_offset(&self) -> usize {
+        self.scroll_offset
+    }
+}
+
+/// How the menu expects the display to be refreshed
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RenderMode {
+    /// Redraw as soon as anything changes
+    Standard,
+    /// For displays where every refresh is slow and visible, such as e-paper. The list scrolls a
+    /// page at a time so moving the highlight only touches two rows,
+    /// [`Menu::draw_changes`](crate::Menu::draw_changes) does nothing for an unchanged menu, and
+    /// every `full_refresh_interval` draws [`DrawReport::full_refresh_needed`] is set so the
+    /// application can clear ghosting. An interval of 0 never asks for a full refresh.
+    EPaper { full_refresh_interval: u32 },
+}
+
+/// Areas of the draw target changed by [`Menu::draw_changes`](crate::Menu::draw_changes), for
+/// displays that can refresh part of the screen
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DrawReport {
+    regions: [Rectangle; MAX_CHANGED_REGIONS],
+    region_count: usize,
+    viewport: Rectangle,
+    rotation: Rotation,
+    full_refresh_needed: bool,
+}
+
+impl DrawReport {
+    /// Empty report for areas of a menu drawn into `viewport` with `rotation`
+    pub(crate) fn new(viewport: Rectangle, rotation: Rotation) -> Self {
+        Self {
+            regions: [Rectangle::zero(); MAX_CHANGED_REGIONS],
+            region_count: 0,
+            viewport,
+            rotation,
+            full_refresh_needed: false,
+        }
+    }
+
+    /// Add an area given in the coordinates of the rotated menu
+    pub(crate) fn add_region(&mut self, area: Rectangle) {
+        if area.is_zero_sized() {
+            return;
+        }
+        let area = self
+            .rotation
+            .map_rectangle(area, self.viewport.size)
+            .translate(self.viewport.top_left);
+        self.push_region(area);
+    }
+
+    pub(crate) fn add_viewport(&mut self) {
+        self.push_region(self.viewport);
+    }
+
+    fn push_region(&mut self, area: Rectangle) {
+        if self.region_count < MAX_CHANGED_REGIONS {
+            self.regions[self.region_count] = area;
+            self.region_count += 1;
+        } else {
+            let last = &mut self.regions[MAX_CHANGED_REGIONS - 1];
+            *last = envelope(*last, area);
+        }
+    }
+
+    pub(crate) fn request_full_refresh(&mut self) {
+        self.full_refresh_needed = true;
+    }
+
+    /// Changed areas in the coordinates of the draw target
+    pub fn changed_regions(&self) -> &[Rectangle] {
+        &self.regions[..self.region_count]
+    }
+
+    /// Smallest rectangle covering every changed area
+    pub fn bounding_box(&self) -> Option<Rectangle> {
+        self.changed_regions().iter().copied().reduce(envelope)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.region_count == 0
+    }
+
+    /// The display should do a full refresh instead of a partial one, see [`RenderMode::EPaper`]
+    pub fn full_refresh_needed(&self) -> bool {
+        self.full_refresh_needed
+    }
+}
+
+fn envelope(a: Rectangle, b: Rectangle) -> Rectangle {
+    match (a.bottom_right(), b.bottom_right()) {
+        (Some(a_bottom_right), Some(b_bottom_right)) => Rectangle::with_corners(
+            a.top_left.component_min(b.top_left),
+            a_bottom_right.component_max(b_bottom_right),
+        ),
+        (Some(_), None) => a,
+        _ => b,
+    }
+}
@@ -0,0 +1,180 @@
+use embedded_graphics::draw_target::DrawTarget;
+use embedded_graphics::geometry::{OriginDimensions, Point, Size};
+use embedded_graphics::primitives::Rectangle;
+use embedded_graphics::Pixel;
+
+/// Clockwise rotation of the menu on the display, for displays mounted sideways or upside down
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Rotation {
+    Deg0,
+    Deg90,
+    Deg180,
+    Deg270,
+}
+
+impl Rotation {
+    /// Size of the menu when drawn rotated onto an area of `size`
+    pub fn rotate_size(self, size: Size) -> Size {
+        match self {
+            Rotation::Deg0 | Rotation::Deg180 => size,
+            Rotation::Deg90 | Rotation::Deg270 => Size::new(size.height, size.width),
+        }
+    }
+
+    /// Map a point of the menu to the point of an area `size` it is drawn on
+    pub(crate) fn map_point(self, point: Point, size: Size) -> Point {
+        let right = size.width as i32 - 1;
+        let bottom = size.height as i32 - 1;
+        match self {
+            Rotation::Deg0 => point,
+            Rotation::Deg90 => Point::new(right - point.y, point.x),
+            Rotation::Deg180 => Point::new(right - point.x, bottom - point.y),
+            Rotation::Deg270 => Point::new(point.y, bottom - point.x),
+        }
+    }
+
+    pub(crate) fn map_rectangle(self, area: Rectangle, size: Size) -> Rectangle {
+        match area.bottom_right() {
+            Some(bottom_right) => {
+                let top_left = self.map_point(area.top_left, size);
+                let bottom_right = self.map_point(bottom_right, size);
+                Rectangle::with_corners(
+                    top_left.component_min(bottom_right),
+                    top_left.component_max(bottom_right),
+                )
+            }
+            None => Rectangle::new(self.map_point(area.top_left, size), Size::zero()),
+        }
+    }
+}
+
+/// Draw target that rotates everything drawn on it onto a parent target whose bounding box
+/// starts at the origin
+pub(crate) struct RotatedTarget<'d, D> {
+    parent: &'d mut D,
+    rotation: Rotation,
+    parent_size: Size,
+}
+
+impl<'d, D> RotatedTarget<'d, D>
+where
+    D: DrawTarget,
+{
+    pub fn new(parent: &'d mut D, rotation: Rotation) -> Self {
+        let parent_size = parent.bounding_box().size;
+        Self {
+            parent,
+            rotation,
+            parent_size,
+        }
+    }
+}
+
+impl<D> OriginDimensions for RotatedTarget<'_, D> {
+    fn size(&self) -> Size {
+        self.rotation.rotate_size(self.parent_size)
+    }
+}
+
+impl<D> DrawTarget for RotatedTarget<'_, D>
+where
+    D: DrawTarget,
+{
+    type Color = D::Color;
+    type Error = D::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let rotation = self.rotation;
+        let parent_size = self.parent_size;
+        self.parent.draw_iter(
+            pixels
+                .into_iter()
+                .map(|Pixel(point, color)| Pixel(rotation.map_point(point, parent_size), color)),
+        )
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        let area = self.rotation.map_rectangle(*area, self.parent_size);
+        self.parent.fill_solid(&area, color)
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.parent.clear(color)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::draw_target::DrawTargetExt;
+    use embedded_graphics::mock_display::MockDisplay;
+    use embedded_graphics::pixelcolor::BinaryColor;
+    use embedded_graphics::primitives::PointsIter;
+
+    /// Area 3 by 2 pixels the rotated menu is drawn onto
+    const PARENT_AREA: Rectangle = Rectangle::new(Point::zero(), Size::new(3, 2));
+
+    /// The top left pixel of the menu drawn on, with the one to its right drawn off to show which
+    /// way the menu's x axis points
+    fn draw_corner(rotation: Rotation) -> MockDisplay<BinaryColor> {
+        let mut display = MockDisplay::new();
+        let mut parent = display.cropped(&PARENT_AREA);
+        let mut rotated = RotatedTarget::new(&mut parent, rotation);
+        rotated
+            .draw_iter([
+                Pixel(Point::new(0, 0), BinaryColor::On),
+                Pixel(Point::new(1, 0), BinaryColor::Off),
+            ])
+            .unwrap();
+        display
+    }
+
+    #[test]
+    fn rotated_targets_swap_width_and_height_when_turned_sideways() {
+        let mut display = MockDisplay::<BinaryColor>::new();
+        let mut parent = display.cropped(&PARENT_AREA);
+        for (rotation, size) in [
+            (Rotation::Deg0, Size::new(3, 2)),
+            (Rotation::Deg90, Size::new(2, 3)),
+            (Rotation::Deg180, Size::new(3, 2)),
+            (Rotation::Deg270, Size::new(2, 3)),
+        ] {
+            assert_eq!(RotatedTarget::new(&mut parent, rotation).size(), size);
+        }
+    }
+
+    #[test]
+    fn pixels_are_turned_clockwise() {
+        draw_corner(Rotation::Deg0).assert_pattern(&["#.", "  "]);
+        draw_corner(Rotation::Deg90).assert_pattern(&["  #", "  ."]);
+        draw_corner(Rotation::Deg180).assert_pattern(&["   ", " .#"]);
+        draw_corner(Rotation::Deg270).assert_pattern(&[".", "#"]);
+    }
+
+    #[test]
+    fn filled_areas_land_where_their_pixels_would() {
+        let area = Rectangle::new(Point::new(0, 1), Size::new(2, 1));
+        for rotation in [
+            Rotation::Deg0,
+            Rotation::Deg90,
+            Rotation::Deg180,
+            Rotation::Deg270,
+        ] {
+            let mut filled = MockDisplay::new();
+            let mut parent = filled.cropped(&PARENT_AREA);
+            RotatedTarget::new(&mut parent, rotation)
+                .fill_solid(&area, BinaryColor::On)
+                .unwrap();
+
+            let mut drawn = MockDisplay::new();
+            let mut parent = drawn.cropped(&PARENT_AREA);
+            RotatedTarget::new(&mut parent, rotation)
+                .draw_iter(area.points().map(|point| Pixel(point, BinaryColor::On)))
+                .unwrap();
+            filled.assert_eq(&drawn);
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use core::fmt;
+
+/// Fixed size text for values formatted at runtime, such as numbers, without an allocator.
+/// Text past the capacity is dropped.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct TextBuffer<const N: usize> {
+    bytes: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> TextBuffer<N> {
+    pub const fn new() -> Self {
+        Self {
+            bytes: [0; N],
+            len: 0,
+        }
+    }
+
+    /// Text formatted from `args`
+    pub fn from_fmt(args: fmt::Arguments<'_>) -> Self {
+        let mut buffer = Self::new();
+        // Writing never fails, it truncates instead
+        let _ = fmt::Write::write_fmt(&mut buffer, args);
+        buffer
+    }
+
+    pub fn as_str(&self) -> &str {
+        // Only whole characters are copied in so the bytes are always valid UTF-8
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap_or("")
+    }
+}
+
+impl<const N: usize> fmt::Write for TextBuffer<N> {
+    fn write_str(&mut self, text: &str) -> fmt::Result {
+        for character in text.chars() {
+            let character_len = character.len_utf8();
+            if self.len + character_len > N {
+                break;
+            }
+            character.encode_utf8(&mut self.bytes[self.len..]);
+            self.len += character_len;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,172 @@
+use embedded_graphics::pixelcolor::{BinaryColor, Gray4, Rgb565, RgbColor};
+
+/// Set of colours used to build a [`MenuStyle`](crate::MenuStyle) with
+/// [`MenuStyleBuilder`](crate::MenuStyleBuilder). Ready-made light, dark and high contrast themes
+/// are provided for `BinaryColor`, `Gray4` and `Rgb565`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MenuTheme<C> {
+    pub background: C,
+    pub heading: C,
+    pub header_background: Option<C>,
+    pub separator: Option<C>,
+    pub item: C,
+    pub value: C,
+    pub disabled: C,
+    pub indicator: C,
+    pub highlight: C,
+    pub highlight_text: C,
+    pub highlight_indicator: C,
+}
+
+/// Colour types with ready-made themes, available through [`MenuTheme::dark`],
+/// [`MenuTheme::light`] and [`MenuTheme::high_contrast`]
+pub trait ThemePresets: Sized {
+    const DARK: MenuTheme<Self>;
+    const LIGHT: MenuTheme<Self>;
+    const HIGH_CONTRAST: MenuTheme<Self>;
+}
+
+impl<C: ThemePresets> MenuTheme<C> {
+    pub const fn dark() -> Self {
+        C::DARK
+    }
+
+    pub const fn light() -> Self {
+        C::LIGHT
+    }
+
+    pub const fn high_contrast() -> Self {
+        C::HIGH_CONTRAST
+    }
+}
+
+impl ThemePresets for BinaryColor {
+    /// Lit pixels on a dark background, as on most monochrome OLEDs
+    const DARK: MenuTheme<Self> = MenuTheme {
+        background: BinaryColor::Off,
+        heading: BinaryColor::On,
+        header_background: None,
+        separator: None,
+        item: BinaryColor::On,
+        value: BinaryColor::On,
+        disabled: BinaryColor::On,
+        indicator: BinaryColor::On,
+        highlight: BinaryColor::On,
+        highlight_text: BinaryColor::Off,
+        highlight_indicator: BinaryColor::Off,
+    };
+
+    /// Dark pixels on a lit background, as on reflective LCDs and e-paper
+    const LIGHT: MenuTheme<Self> = MenuTheme {
+        background: BinaryColor::On,
+        heading: BinaryColor::Off,
+        header_background: None,
+        separator: None,
+        item: BinaryColor::Off,
+        value: BinaryColor::Off,
+        disabled: BinaryColor::Off,
+        indicator: BinaryColor::Off,
+        highlight: BinaryColor::Off,
+        highlight_text: BinaryColor::On,
+        highlight_indicator: BinaryColor::On,
+    };
+
+    /// Dark theme with an inverted header bar and separator lines
+    const HIGH_CONTRAST: MenuTheme<Self> = MenuTheme {
+        heading: BinaryColor::Off,
+        header_background: Some(BinaryColor::On),
+        separator: Some(BinaryColor::On),
+        ..Self::DARK
+    };
+}
+
+impl ThemePresets for Gray4 {
+    const DARK: MenuTheme<Self> = MenuTheme {
+        background: Gray4::new(0),
+        heading: Gray4::new(15),
+        header_background: Some(Gray4::new(3)),
+        separator: Some(Gray4::new(5)),
+        item: Gray4::new(12),
+        value: Gray4::new(15),
+        disabled: Gray4::new(6),
+        indicator: Gray4::new(10),
+        highlight: Gray4::new(10),
+        highlight_text: Gray4::new(0),
+        highlight_indicator: Gray4::new(0),
+    };
+
+    const LIGHT: MenuTheme<Self> = MenuTheme {
+        background: Gray4::new(15),
+        heading: Gray4::new(0),
+        header_background: Some(Gray4::new(12)),
+        separator: Some(Gray4::new(10)),
+        item: Gray4::new(2),
+        value: Gray4::new(0),
+        disabled: Gray4::new(8),
+        indicator: Gray4::new(4),
+        highlight: Gray4::new(4),
+        highlight_text: Gray4::new(15),
+        highlight_indicator: Gray4::new(15),
+    };
+
+    const HIGH_CONTRAST: MenuTheme<Self> = MenuTheme {
+        background: Gray4::new(0),
+        heading: Gray4::new(0),
+        header_background: Some(Gray4::new(15)),
+        separator: Some(Gray4::new(15)),
+        item: Gray4::new(15),
+        value: Gray4::new(15),
+        disabled: Gray4::new(8),
+        indicator: Gray4::new(15),
+        highlight: Gray4::new(15),
+        highlight_text: Gray4::new(0),
+        highlight_indicator: Gray4::new(0),
+    };
+}
+
+impl ThemePresets for Rgb565 {
+    /// Light grey text on a dark slate background with a teal highlight
+    const DARK: MenuTheme<Self> = MenuTheme {
+        background: Rgb565::new(2, 5, 3),
+        heading: Rgb565::WHITE,
+        header_background: Some(Rgb565::new(5, 11, 8)),
+        separator: Some(Rgb565::new(8, 16, 10)),
+        item: Rgb565::new(26, 52, 26),
+        value: Rgb565::new(12, 48, 31),
+        disabled: Rgb565::new(14, 28, 14),
+        indicator: Rgb565::new(26, 52, 26),
+        highlight: Rgb565::new(3, 29, 24),
+        highlight_text: Rgb565::WHITE,
+        highlight_indicator: Rgb565::WHITE,
+    };
+
+    /// Dark text on a white background with a teal highlight
+    const LIGHT: MenuTheme<Self> = MenuTheme {
+        background: Rgb565::WHITE,
+        heading: Rgb565::new(2, 5, 3),
+        header_background: Some(Rgb565::new(27, 54, 27)),
+        separator: Some(Rgb565::new(22, 44, 22)),
+        item: Rgb565::new(4, 8, 4),
+        value: Rgb565::new(0, 24, 20),
+        disabled: Rgb565::new(18, 36, 18),
+        indicator: Rgb565::new(4, 8, 4),
+        highlight: Rgb565::new(3, 29, 24),
+        highlight_text: Rgb565::WHITE,
+        highlight_indicator: Rgb565::WHITE,
+    };
+
+    /// White and yellow on black, with a yellow header bar and highlight
+    const HIGH_CONTRAST: MenuTheme<Self> = MenuTheme {
+        background: Rgb565::BLACK,
+        heading: Rgb565::BLACK,
+        header_background: Some(Rgb565::YELLOW),
+        separator: Some(Rgb565::WHITE),
+        item: Rgb565::WHITE,
+        value: Rgb565::YELLOW,
+        disabled: Rgb565::new(16, 32, 16),
+        indicator: Rgb565::WHITE,
+        highlight: Rgb565::YELLOW,
+        highlight_text: Rgb565::BLACK,
+        highlight_indicator: Rgb565::BLACK,
+    };
+}